@@ -17,6 +17,7 @@ use smol_str::SmolStr;
 use rocstr::RocStr;
 
 const TIME: u64 = 100;
+const VEC_LEN: usize = 1024;
 
 fn generic_bench_clone<T>(group: &mut BenchmarkGroup<WallTime>, param: &'static str)
 where
@@ -33,6 +34,23 @@ where
     }
 }
 
+fn generic_bench_vec_clone<T>(group: &mut BenchmarkGroup<WallTime>, param: &'static str)
+where
+    T: TryFrom<&'static str> + Clone + EqStr + Name,
+{
+    if let Ok(p) = T::try_from(param) {
+        if p.eq(param) {
+            let values = vec![p; VEC_LEN];
+            let id = format!("{}/{}B", T::name(), core::mem::size_of::<T>());
+            group.bench_with_input(
+                BenchmarkId::new(id, param.len()),
+                &values,
+                |b: &mut Bencher<WallTime>, values: &Vec<T>| b.iter(|| values.clone()),
+            );
+        }
+    }
+}
+
 fn bench_clones(c: &mut Criterion) {
     let params = [
         (""),
@@ -88,7 +106,45 @@ fn bench_clones(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(strings, bench_clones);
+fn bench_vec_clones(c: &mut Criterion) {
+    let params = [
+        (core::str::from_utf8(&[b'a'; 8]).unwrap()),
+        (core::str::from_utf8(&[b'b'; 16]).unwrap()),
+        (core::str::from_utf8(&[b'c'; 32]).unwrap()),
+        (core::str::from_utf8(&[b'd'; 64]).unwrap()),
+    ];
+    let mut group = c.benchmark_group("vec_clone");
+    group.measurement_time(Duration::from_millis(TIME));
+    group.warm_up_time(Duration::from_millis(TIME));
+
+    for (i, param) in params.iter().enumerate() {
+        generic_bench_vec_clone::<String>(&mut group, param);
+        generic_bench_vec_clone::<SmolStr>(&mut group, param);
+
+        match i {
+            0 => {
+                generic_bench_vec_clone::<ArrayString<8>>(&mut group, param);
+                generic_bench_vec_clone::<RocStr<8>>(&mut group, param);
+            }
+            1 => {
+                generic_bench_vec_clone::<ArrayString<16>>(&mut group, param);
+                generic_bench_vec_clone::<RocStr<16>>(&mut group, param);
+            }
+            2 => {
+                generic_bench_vec_clone::<ArrayString<32>>(&mut group, param);
+                generic_bench_vec_clone::<RocStr<32>>(&mut group, param);
+            }
+            _ => {
+                generic_bench_vec_clone::<ArrayString<64>>(&mut group, param);
+                generic_bench_vec_clone::<RocStr<64>>(&mut group, param);
+            }
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(strings, bench_clones, bench_vec_clones);
 criterion_main!(strings);
 
 trait Name {
@@ -0,0 +1,525 @@
+//! RocStr identifier cases : conversions between snake_case, kebab-case, SCREAMING_SNAKE_CASE, camelCase and PascalCase
+//!
+//! Words are split on every non alphanumeric character, and on case changes,
+//! like the [heck](https://crates.io/crates/heck) crate :
+//!
+//! * a lowercase character followed by an uppercase one ends a word, `camelCase` is `camel` and `Case`
+//! * an uppercase character followed by a lowercase one starts a word, unless it is preceded by a lowercase one,
+//!   `XMLHttpRequest` is `XML`, `Http` and `Request`
+//! * digits belong to the word they are in, `abc123Def456` is `abc123` and `Def456`
+//!
+//! Every conversion silently trims its result to at most the capacity,
+//! and has a `try_` variant which fails instead.
+//!
+//! # Examples
+//! ```
+//! # use rocstr::case;
+//! # use rocstr::RocStr;
+//! let field: RocStr<32> = case::to_snake_case("XMLHttpRequest");
+//! assert_eq!(field, "xml_http_request");
+//!
+//! let field: RocStr<32> = case::to_camel_case("customer_first_name");
+//! assert_eq!(field, "customerFirstName");
+//! ```
+
+use crate::rocbuilder::RocStrBuilder;
+use crate::rocerr::InsufficientCapacity;
+use crate::rocerr::Operation;
+use crate::RocStr;
+
+/// Converts a `&str` to snake_case.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::to_snake_case;
+/// # use rocstr::RocStr;
+/// let s: RocStr<16> = to_snake_case("FieldName11");
+/// assert_eq!(s, "field_name11");
+/// ```
+#[inline]
+#[must_use]
+pub fn to_snake_case<const SIZE: usize>(value: &str) -> RocStr<SIZE> {
+    transform(value, lowercase, "_").finish()
+}
+
+/// Converts a `&str` to snake_case, failing if the result does not fit in the capacity.
+///
+/// # Errors
+///
+/// Returns [`InsufficientCapacity`] if the result is longer than `SIZE`.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::try_to_snake_case;
+/// assert_eq!(try_to_snake_case::<16>("FieldName").unwrap(), "field_name");
+/// assert_eq!(try_to_snake_case::<9>("FieldName").unwrap_err().required, 10);
+/// ```
+#[inline]
+pub fn try_to_snake_case<const SIZE: usize>(
+    value: &str,
+) -> Result<RocStr<SIZE>, InsufficientCapacity> {
+    transform(value, lowercase, "_").try_finish_with(Operation::ToCase)
+}
+
+/// Converts a `&str` to kebab-case.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::to_kebab_case;
+/// # use rocstr::RocStr;
+/// let s: RocStr<16> = to_kebab_case("FieldName11");
+/// assert_eq!(s, "field-name11");
+/// ```
+#[inline]
+#[must_use]
+pub fn to_kebab_case<const SIZE: usize>(value: &str) -> RocStr<SIZE> {
+    transform(value, lowercase, "-").finish()
+}
+
+/// Converts a `&str` to kebab-case, failing if the result does not fit in the capacity.
+///
+/// # Errors
+///
+/// Returns [`InsufficientCapacity`] if the result is longer than `SIZE`.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::try_to_kebab_case;
+/// assert_eq!(try_to_kebab_case::<16>("FieldName").unwrap(), "field-name");
+/// assert_eq!(try_to_kebab_case::<9>("FieldName").unwrap_err().required, 10);
+/// ```
+#[inline]
+pub fn try_to_kebab_case<const SIZE: usize>(
+    value: &str,
+) -> Result<RocStr<SIZE>, InsufficientCapacity> {
+    transform(value, lowercase, "-").try_finish_with(Operation::ToCase)
+}
+
+/// Converts a `&str` to SCREAMING_SNAKE_CASE.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::to_screaming_snake_case;
+/// # use rocstr::RocStr;
+/// let s: RocStr<16> = to_screaming_snake_case("FieldName11");
+/// assert_eq!(s, "FIELD_NAME11");
+/// ```
+#[inline]
+#[must_use]
+pub fn to_screaming_snake_case<const SIZE: usize>(value: &str) -> RocStr<SIZE> {
+    transform(value, uppercase, "_").finish()
+}
+
+/// Converts a `&str` to SCREAMING_SNAKE_CASE, failing if the result does not fit in the capacity.
+///
+/// # Errors
+///
+/// Returns [`InsufficientCapacity`] if the result is longer than `SIZE`.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::try_to_screaming_snake_case;
+/// assert_eq!(try_to_screaming_snake_case::<16>("FieldName").unwrap(), "FIELD_NAME");
+/// assert_eq!(try_to_screaming_snake_case::<9>("FieldName").unwrap_err().required, 10);
+/// ```
+#[inline]
+pub fn try_to_screaming_snake_case<const SIZE: usize>(
+    value: &str,
+) -> Result<RocStr<SIZE>, InsufficientCapacity> {
+    transform(value, uppercase, "_").try_finish_with(Operation::ToCase)
+}
+
+/// Converts a `&str` to camelCase, the first word is lowercase and the next ones are capitalized.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::to_camel_case;
+/// # use rocstr::RocStr;
+/// let s: RocStr<16> = to_camel_case("field_name_11");
+/// assert_eq!(s, "fieldName11");
+/// ```
+#[inline]
+#[must_use]
+pub fn to_camel_case<const SIZE: usize>(value: &str) -> RocStr<SIZE> {
+    transform(value, lower_camel, "").finish()
+}
+
+/// Converts a `&str` to camelCase, failing if the result does not fit in the capacity.
+///
+/// # Errors
+///
+/// Returns [`InsufficientCapacity`] if the result is longer than `SIZE`.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::try_to_camel_case;
+/// assert_eq!(try_to_camel_case::<16>("field_name").unwrap(), "fieldName");
+/// assert_eq!(try_to_camel_case::<8>("field_name").unwrap_err().required, 9);
+/// ```
+#[inline]
+pub fn try_to_camel_case<const SIZE: usize>(
+    value: &str,
+) -> Result<RocStr<SIZE>, InsufficientCapacity> {
+    transform(value, lower_camel, "").try_finish_with(Operation::ToCase)
+}
+
+/// Converts a `&str` to PascalCase, every word is capitalized.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::to_pascal_case;
+/// # use rocstr::RocStr;
+/// let s: RocStr<16> = to_pascal_case("field_name_11");
+/// assert_eq!(s, "FieldName11");
+/// ```
+#[inline]
+#[must_use]
+pub fn to_pascal_case<const SIZE: usize>(value: &str) -> RocStr<SIZE> {
+    transform(value, capitalize, "").finish()
+}
+
+/// Converts a `&str` to PascalCase, failing if the result does not fit in the capacity.
+///
+/// # Errors
+///
+/// Returns [`InsufficientCapacity`] if the result is longer than `SIZE`.
+///
+/// # Examples
+/// ```
+/// # use rocstr::case::try_to_pascal_case;
+/// assert_eq!(try_to_pascal_case::<16>("field_name").unwrap(), "FieldName");
+/// assert_eq!(try_to_pascal_case::<8>("field_name").unwrap_err().required, 9);
+/// ```
+#[inline]
+pub fn try_to_pascal_case<const SIZE: usize>(
+    value: &str,
+) -> Result<RocStr<SIZE>, InsufficientCapacity> {
+    transform(value, capitalize, "").try_finish_with(Operation::ToCase)
+}
+
+/// The way a word is written, given its index.
+type WordWriter<const SIZE: usize> = fn(&mut RocStrBuilder<SIZE>, usize, &str);
+
+/// The case of the last character seen in a word, digits do not change it.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Mode {
+    Boundary,
+    Lowercase,
+    Uppercase,
+}
+
+/// Splits `value` in words, writing them with `word` separated by `separator`.
+fn transform<const SIZE: usize>(
+    value: &str,
+    word: WordWriter<SIZE>,
+    separator: &str,
+) -> RocStrBuilder<SIZE> {
+    let mut builder = RocStrBuilder::new();
+    let mut index = 0;
+    let mut write = |builder: &mut RocStrBuilder<SIZE>, value: &str| {
+        if index > 0 {
+            builder.push_str(separator);
+        }
+        word(builder, index, value);
+        index += 1;
+    };
+
+    for chunk in value.split(|c: char| !c.is_alphanumeric()) {
+        let mut chars = chunk.char_indices().peekable();
+        let mut start = 0;
+        let mut mode = Mode::Boundary;
+        while let Some((i, c)) = chars.next() {
+            if let Some(&(next_i, next)) = chars.peek() {
+                let next_mode = if c.is_lowercase() {
+                    Mode::Lowercase
+                } else if c.is_uppercase() {
+                    Mode::Uppercase
+                } else {
+                    mode
+                };
+
+                if next_mode == Mode::Lowercase && next.is_uppercase() {
+                    // `camelCase` : a word ends after a lowercase followed by an uppercase
+                    write(&mut builder, &chunk[start..next_i]);
+                    start = next_i;
+                    mode = Mode::Boundary;
+                } else if mode == Mode::Uppercase && c.is_uppercase() && next.is_lowercase() {
+                    // `XMLHttp` : a word starts before an uppercase followed by a lowercase
+                    write(&mut builder, &chunk[start..i]);
+                    start = i;
+                    mode = Mode::Boundary;
+                } else {
+                    mode = next_mode;
+                }
+            } else {
+                write(&mut builder, &chunk[start..]);
+            }
+        }
+    }
+
+    builder
+}
+
+fn lowercase<const SIZE: usize>(builder: &mut RocStrBuilder<SIZE>, _: usize, word: &str) {
+    word.chars()
+        .flat_map(char::to_lowercase)
+        .for_each(|c| builder.push(c));
+}
+
+fn uppercase<const SIZE: usize>(builder: &mut RocStrBuilder<SIZE>, _: usize, word: &str) {
+    word.chars()
+        .flat_map(char::to_uppercase)
+        .for_each(|c| builder.push(c));
+}
+
+fn capitalize<const SIZE: usize>(builder: &mut RocStrBuilder<SIZE>, index: usize, word: &str) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        first.to_uppercase().for_each(|c| builder.push(c));
+        lowercase(builder, index, chars.as_str());
+    }
+}
+
+fn lower_camel<const SIZE: usize>(builder: &mut RocStrBuilder<SIZE>, index: usize, word: &str) {
+    if index == 0 {
+        lowercase(builder, index, word);
+    } else {
+        capitalize(builder, index, word);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: [&str; 22] = [
+        "CamelCase",
+        "This is Human case.",
+        "MixedUP CamelCase, with some Spaces",
+        "mixed_up_ snake_case with some _spaces",
+        "kebab-case",
+        "SHOUTY_SNAKE_CASE",
+        "snake_case",
+        "XMLHttpRequest",
+        "FIELD_NAME11",
+        "99BOTTLES",
+        "FieldNamE11",
+        "abc123def456",
+        "abc123DEF456",
+        "abc123Def456",
+        "abc123DEf456",
+        "ABC123def456",
+        "ABC123DEF456",
+        "ABC123Def456",
+        "ABC123DEf456",
+        "ABC123dEEf456FOO",
+        "abcDEF",
+        "ABcDE",
+    ];
+
+    fn assert_conversions(convert: fn(&str) -> RocStr<64>, expected: [&str; 22]) {
+        for (sample, expected) in SAMPLES.iter().zip(expected.iter()) {
+            assert_eq!(convert(sample), *expected, "converting {sample:?}");
+        }
+    }
+
+    #[test]
+    fn to_snake_case_should_split_words_like_heck() {
+        assert_conversions(
+            to_snake_case,
+            [
+                "camel_case",
+                "this_is_human_case",
+                "mixed_up_camel_case_with_some_spaces",
+                "mixed_up_snake_case_with_some_spaces",
+                "kebab_case",
+                "shouty_snake_case",
+                "snake_case",
+                "xml_http_request",
+                "field_name11",
+                "99bottles",
+                "field_nam_e11",
+                "abc123def456",
+                "abc123_def456",
+                "abc123_def456",
+                "abc123_d_ef456",
+                "abc123def456",
+                "abc123def456",
+                "abc123_def456",
+                "abc123d_ef456",
+                "abc123d_e_ef456_foo",
+                "abc_def",
+                "a_bc_de",
+            ],
+        );
+    }
+
+    #[test]
+    fn to_kebab_case_should_split_words_like_heck() {
+        assert_conversions(
+            to_kebab_case,
+            [
+                "camel-case",
+                "this-is-human-case",
+                "mixed-up-camel-case-with-some-spaces",
+                "mixed-up-snake-case-with-some-spaces",
+                "kebab-case",
+                "shouty-snake-case",
+                "snake-case",
+                "xml-http-request",
+                "field-name11",
+                "99bottles",
+                "field-nam-e11",
+                "abc123def456",
+                "abc123-def456",
+                "abc123-def456",
+                "abc123-d-ef456",
+                "abc123def456",
+                "abc123def456",
+                "abc123-def456",
+                "abc123d-ef456",
+                "abc123d-e-ef456-foo",
+                "abc-def",
+                "a-bc-de",
+            ],
+        );
+    }
+
+    #[test]
+    fn to_screaming_snake_case_should_split_words_like_heck() {
+        assert_conversions(
+            to_screaming_snake_case,
+            [
+                "CAMEL_CASE",
+                "THIS_IS_HUMAN_CASE",
+                "MIXED_UP_CAMEL_CASE_WITH_SOME_SPACES",
+                "MIXED_UP_SNAKE_CASE_WITH_SOME_SPACES",
+                "KEBAB_CASE",
+                "SHOUTY_SNAKE_CASE",
+                "SNAKE_CASE",
+                "XML_HTTP_REQUEST",
+                "FIELD_NAME11",
+                "99BOTTLES",
+                "FIELD_NAM_E11",
+                "ABC123DEF456",
+                "ABC123_DEF456",
+                "ABC123_DEF456",
+                "ABC123_D_EF456",
+                "ABC123DEF456",
+                "ABC123DEF456",
+                "ABC123_DEF456",
+                "ABC123D_EF456",
+                "ABC123D_E_EF456_FOO",
+                "ABC_DEF",
+                "A_BC_DE",
+            ],
+        );
+    }
+
+    #[test]
+    fn to_camel_case_should_split_words_like_heck() {
+        assert_conversions(
+            to_camel_case,
+            [
+                "camelCase",
+                "thisIsHumanCase",
+                "mixedUpCamelCaseWithSomeSpaces",
+                "mixedUpSnakeCaseWithSomeSpaces",
+                "kebabCase",
+                "shoutySnakeCase",
+                "snakeCase",
+                "xmlHttpRequest",
+                "fieldName11",
+                "99bottles",
+                "fieldNamE11",
+                "abc123def456",
+                "abc123Def456",
+                "abc123Def456",
+                "abc123DEf456",
+                "abc123def456",
+                "abc123def456",
+                "abc123Def456",
+                "abc123dEf456",
+                "abc123dEEf456Foo",
+                "abcDef",
+                "aBcDe",
+            ],
+        );
+    }
+
+    #[test]
+    fn to_pascal_case_should_split_words_like_heck() {
+        assert_conversions(
+            to_pascal_case,
+            [
+                "CamelCase",
+                "ThisIsHumanCase",
+                "MixedUpCamelCaseWithSomeSpaces",
+                "MixedUpSnakeCaseWithSomeSpaces",
+                "KebabCase",
+                "ShoutySnakeCase",
+                "SnakeCase",
+                "XmlHttpRequest",
+                "FieldName11",
+                "99bottles",
+                "FieldNamE11",
+                "Abc123def456",
+                "Abc123Def456",
+                "Abc123Def456",
+                "Abc123DEf456",
+                "Abc123def456",
+                "Abc123def456",
+                "Abc123Def456",
+                "Abc123dEf456",
+                "Abc123dEEf456Foo",
+                "AbcDef",
+                "ABcDe",
+            ],
+        );
+    }
+
+    #[test]
+    fn case_conversion_should_handle_unicode_words() {
+        let s: RocStr<32> = to_snake_case("LöweTiger 老虎");
+        assert_eq!(s, "löwe_tiger_老虎");
+
+        let s: RocStr<32> = to_pascal_case("élan_vital");
+        assert_eq!(s, "ÉlanVital");
+    }
+
+    #[test]
+    fn case_conversion_of_empty_or_separators_only_should_be_empty() {
+        assert_eq!(to_snake_case::<8>(""), "");
+        assert_eq!(to_camel_case::<8>("_-_ "), "");
+    }
+
+    #[test]
+    fn case_conversion_without_enough_capacity_should_be_truncated() {
+        assert_eq!(to_snake_case::<8>("XMLHttpRequest"), "xml_http");
+        assert_eq!(to_pascal_case::<8>("xml_http_request"), "XmlHttpR");
+    }
+
+    #[test]
+    fn try_case_conversion_without_enough_capacity_should_fail() {
+        assert_eq!(
+            try_to_snake_case::<8>("XMLHttpRequest"),
+            Err(InsufficientCapacity::new(Operation::ToCase, 16, 8, 8))
+        );
+        assert_eq!(
+            try_to_kebab_case::<8>("XMLHttpRequest"),
+            Err(InsufficientCapacity::new(Operation::ToCase, 16, 8, 8))
+        );
+        assert_eq!(
+            try_to_screaming_snake_case::<8>("XMLHttpRequest"),
+            Err(InsufficientCapacity::new(Operation::ToCase, 16, 8, 8))
+        );
+        assert_eq!(
+            try_to_camel_case::<8>("XMLHttpRequest"),
+            Err(InsufficientCapacity::new(Operation::ToCase, 14, 8, 8))
+        );
+        assert_eq!(
+            try_to_pascal_case::<8>("XMLHttpRequest"),
+            Err(InsufficientCapacity::new(Operation::ToCase, 14, 8, 8))
+        );
+    }
+}
@@ -10,7 +10,7 @@
 //!
 //! It keeps track of its length, and is parameterized by SIZE for the maximum capacity.
 //!
-//! SIZE is of type usize but is range limited to u32::MAX; attempting to create RocStr with larger capacity will panic.
+//! SIZE is of type usize but is range limited to u32::MAX - 1; attempting to create RocStr with larger capacity will panic.
 //!
 //! ## Usage
 //!
@@ -89,6 +89,11 @@
 //! - serde enables serde Serialize/Deserialize support
 //! - postgres enables PostgreSql type support
 //!
+//! Optionally, the following features can be enabled:
+//! - debug-as-str makes `Debug` print a RocStr like a string literal, `"foo"`,
+//!   instead of `RocStr { inner: "foo", len: 3 }`
+//! - serde-strict makes serde Deserialize reject strings longer than the capacity, instead of trimming them
+//!
 //! RocStr supports no_std mode (enabled via default-features = false)
 //!
 //! ## License
@@ -100,14 +105,29 @@
 #![forbid(unsafe_code)]
 #![no_std]
 
+pub mod case;
+pub mod rocbuilder;
 pub mod rocerr;
+pub mod rocint;
+pub mod rocsplit;
 pub mod rocstr;
 
 #[cfg(feature = "postgres")]
 pub mod postgres;
 
 #[cfg(feature = "serde")]
-pub mod serialize;
+pub mod serde;
+
+/// Former name of the [`serde`](crate::serde) module.
+#[cfg(feature = "serde")]
+#[deprecated(since = "0.6.3", note = "renamed to `rocstr::serde`")]
+pub mod serialize {
+    pub use crate::serde::*;
+}
 
+pub use crate::rocbuilder::RocStrBuilder;
+pub use crate::rocerr::FromBytesError;
 pub use crate::rocerr::InsufficientCapacity;
+pub use crate::rocerr::PieceCountError;
 pub use crate::rocstr::RocStr;
+pub use crate::rocstr::Width;
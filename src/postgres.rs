@@ -1,4 +1,10 @@
 //! Postgres type implementation
+//!
+//! Reading a value longer than the capacity fails with an [`InsufficientCapacity`] error,
+//! unless it is read through the [`Lossy`] wrapper, which truncates it instead.
+//!
+//! `BPCHAR` values, i.e. `char(n)`, have their trailing blank padding trimmed,
+//! as Postgres ignores it when comparing them.
 
 extern crate std;
 
@@ -11,11 +17,67 @@ use postgres_types::IsNull;
 use postgres_types::ToSql;
 use postgres_types::Type;
 
+use crate::InsufficientCapacity;
 use crate::RocStr;
 
+/// A [`RocStr`] read from Postgres, silently truncated to its capacity instead of failing.
+///
+/// # Examples
+/// ```
+/// # use postgres_types::FromSql;
+/// # use postgres_types::Type;
+/// # use rocstr::postgres::Lossy;
+/// # use rocstr::RocStr;
+/// let raw = "Lorem ipsum".as_bytes();
+///
+/// assert!(RocStr::<8>::from_sql(&Type::VARCHAR, raw).is_err());
+///
+/// let Lossy(value) = Lossy::<8>::from_sql(&Type::VARCHAR, raw).unwrap();
+/// assert_eq!(value, "Lorem ip");
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Lossy<const SIZE: usize>(pub RocStr<SIZE>);
+
+impl<const SIZE: usize> Lossy<SIZE> {
+    /// Returns the wrapped [`RocStr`].
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> RocStr<SIZE> {
+        self.0
+    }
+}
+
+impl<const SIZE: usize> From<Lossy<SIZE>> for RocStr<SIZE> {
+    #[inline]
+    fn from(value: Lossy<SIZE>) -> Self {
+        value.0
+    }
+}
+
+impl<const SIZE: usize> From<RocStr<SIZE>> for Lossy<SIZE> {
+    #[inline]
+    fn from(value: RocStr<SIZE>) -> Self {
+        Self(value)
+    }
+}
+
+/// Reads the text of a Postgres value, without the trailing blank padding of `BPCHAR` values.
+fn text_from_sql<'sql>(
+    ty: &Type,
+    raw: &'sql [u8],
+) -> Result<&'sql str, Box<dyn Error + Sync + Send>> {
+    let text = <&str as FromSql>::from_sql(ty, raw)?;
+    if *ty == Type::BPCHAR {
+        Ok(text.trim_end_matches(' '))
+    } else {
+        Ok(text)
+    }
+}
+
 impl<'sql, const SIZE: usize> FromSql<'sql> for RocStr<SIZE> {
     fn from_sql(ty: &Type, raw: &'sql [u8]) -> Result<RocStr<SIZE>, Box<dyn Error + Sync + Send>> {
-        <&str as FromSql>::from_sql(ty, raw).map(RocStr::from)
+        let text = text_from_sql(ty, raw)?;
+        RocStr::try_from_str(text).map_err(|e: InsufficientCapacity| e.into())
     }
 
     fn accepts(ty: &Type) -> bool {
@@ -41,6 +103,34 @@ impl<const SIZE: usize> ToSql for RocStr<SIZE> {
     }
 }
 
+impl<'sql, const SIZE: usize> FromSql<'sql> for Lossy<SIZE> {
+    fn from_sql(ty: &Type, raw: &'sql [u8]) -> Result<Lossy<SIZE>, Box<dyn Error + Sync + Send>> {
+        text_from_sql(ty, raw).map(|text| Lossy(RocStr::from(text)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as FromSql>::accepts(ty)
+    }
+}
+
+impl<const SIZE: usize> ToSql for Lossy<SIZE> {
+    fn to_sql(&self, ty: &Type, w: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        self.0.to_sql(ty, w)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        <&str as ToSql>::accepts(ty)
+    }
+
+    fn to_sql_checked(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        postgres_types::__to_sql_checked(self, ty, out)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bytes::Bytes;
@@ -113,4 +203,59 @@ mod tests {
         let result = value.to_sql_checked(&ty, &mut out);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn rocstr_from_sql_longer_than_capacity_should_fail() {
+        let raw = "Löwe 老虎 Léopard".as_bytes();
+        let ty = Type::VARCHAR;
+
+        let result = RocStr::<16>::from_sql(&ty, raw);
+        let error = result.unwrap_err();
+        let error = error.downcast_ref::<InsufficientCapacity>().unwrap();
+
+        assert_eq!(
+            *error,
+            InsufficientCapacity::new(crate::rocerr::Operation::FromStr, 21, 16, 16)
+        );
+    }
+
+    #[test]
+    fn lossy_from_sql_longer_than_capacity_should_be_truncated() {
+        let raw = "Löwe 老虎 Léopard".as_bytes();
+        let ty = Type::TEXT;
+
+        let result = Lossy::<16>::from_sql(&ty, raw);
+        assert_eq!(result.unwrap().into_inner(), "Löwe 老虎 Lé");
+
+        let result = Lossy::<32>::from_sql(&ty, raw);
+        assert_eq!(RocStr::from(result.unwrap()), "Löwe 老虎 Léopard");
+    }
+
+    #[test]
+    fn rocstr_from_sql_bpchar_should_be_trimmed_of_its_padding() {
+        let raw = b"  FR-75     ";
+
+        let result = RocStr::<8>::from_sql(&Type::BPCHAR, raw);
+        assert_eq!(result.unwrap(), "  FR-75");
+
+        let result = Lossy::<4>::from_sql(&Type::BPCHAR, raw);
+        assert_eq!(result.unwrap().0, "  FR");
+
+        let result = RocStr::<16>::from_sql(&Type::VARCHAR, raw);
+        assert_eq!(result.unwrap(), "  FR-75     ");
+    }
+
+    #[test]
+    fn lossy_to_sql_checked_should_write_the_inner_str() {
+        let value = Lossy(RocStr::<16>::from("foo checked"));
+        let expected = Bytes::from_static(b"foo checked");
+        let mut out = BytesMut::new();
+
+        let result = value.to_sql_checked(&Type::TEXT, &mut out);
+        assert!(result.is_ok());
+        assert_eq!(out, expected);
+
+        let result = value.to_sql_checked(&Type::INT2, &mut out);
+        assert!(result.is_err());
+    }
 }
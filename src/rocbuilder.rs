@@ -0,0 +1,374 @@
+//! RocStr builder : a `core::fmt::Write` buffer which finishes into an immutable [`RocStr`]
+
+use core::fmt::Write;
+
+use crate::rocerr::InsufficientCapacity;
+use crate::rocerr::Operation;
+use crate::rocstr::extract_utf8_within;
+use crate::RocStr;
+
+/// A fixed capacity buffer to build a [`RocStr`] piece by piece, without allocation.
+///
+/// The first string that does not fit is trimmed to a valid utf-8 boundary,
+/// and everything written afterwards is dropped.
+/// The length the string would require without any capacity limit is still tracked,
+/// so that [`try_finish`](Self::try_finish) can report it.
+///
+/// # Examples
+/// ```
+/// # use core::fmt::Write;
+/// # use rocstr::rocbuilder::RocStrBuilder;
+/// let mut builder = RocStrBuilder::<16>::new();
+/// write!(builder, "{}-{:03}", "FR", 75).unwrap();
+///
+/// assert!(!builder.is_truncated());
+/// assert_eq!(builder.finish(), "FR-075");
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct RocStrBuilder<const SIZE: usize> {
+    inner: [u8; SIZE],
+    len: usize,
+    required: usize,
+}
+
+impl<const SIZE: usize> RocStrBuilder<SIZE> {
+    /// Creates an empty builder.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            inner: [0; SIZE],
+            len: 0,
+            required: 0,
+        }
+    }
+
+    /// Returns the number of bytes written so far, and kept.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if nothing has been written so far.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.required == 0
+    }
+
+    /// Returns the number of bytes the string would require without any capacity limit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::rocbuilder::RocStrBuilder;
+    /// let mut builder = RocStrBuilder::<4>::new();
+    /// builder.push_str("foo bar");
+    ///
+    /// assert_eq!(builder.len(), 4);
+    /// assert_eq!(builder.required(), 7);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn required(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the number of bytes which did not fit in the capacity.
+    #[inline]
+    #[must_use]
+    pub const fn dropped(&self) -> usize {
+        self.required - self.len
+    }
+
+    /// Returns `true` if some bytes did not fit in the capacity.
+    #[inline]
+    #[must_use]
+    pub const fn is_truncated(&self) -> bool {
+        self.required > self.len
+    }
+
+    /// Extracts a string slice containing what has been kept so far.
+    pub fn as_str(&self) -> &str {
+        match core::str::from_utf8(&self.inner[..self.len]) {
+            Ok(string) => string,
+            // Only whole utf-8 strings, or their prefix up to a utf-8 boundary, are copied.
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Appends a `&str`.
+    ///
+    /// Once the builder is truncated, the string is only counted in [`required`](Self::required).
+    pub fn push_str(&mut self, value: &str) {
+        if !self.is_truncated() {
+            let slice = extract_utf8_within(value.as_bytes(), SIZE - self.len);
+            let end = self.len + slice.len();
+            self.inner[self.len..end].copy_from_slice(slice);
+            self.len = end;
+        }
+        self.required += value.len();
+    }
+
+    /// Appends a [`char`].
+    pub fn push(&mut self, value: char) {
+        let mut buffer = [0; 4];
+        self.push_str(value.encode_utf8(&mut buffer));
+    }
+
+    /// Finishes into a [`RocStr`], silently trimmed to at most the capacity.
+    #[inline]
+    #[must_use]
+    pub fn finish(self) -> RocStr<SIZE> {
+        RocStr::from_parts(self.inner, self.len)
+    }
+
+    /// Finishes into a [`RocStr`], failing if anything did not fit in the capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if more than `SIZE` bytes have been written.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::rocbuilder::RocStrBuilder;
+    /// let mut builder = RocStrBuilder::<4>::new();
+    /// builder.push_str("foo bar");
+    ///
+    /// let error = builder.try_finish().unwrap_err();
+    /// assert_eq!(error.required, 7);
+    /// assert_eq!(error.truncated_at, 4);
+    /// ```
+    #[inline]
+    pub fn try_finish(self) -> Result<RocStr<SIZE>, InsufficientCapacity> {
+        self.try_finish_with(Operation::Format)
+    }
+
+    /// Finishes into a [`RocStr`] silently trimmed to at most the capacity,
+    /// along with the number of bytes that did not fit.
+    #[inline]
+    #[must_use]
+    pub fn overflowing_finish(self) -> (RocStr<SIZE>, usize) {
+        let dropped = self.dropped();
+        (self.finish(), dropped)
+    }
+
+    pub(crate) fn try_finish_with(
+        self,
+        operation: Operation,
+    ) -> Result<RocStr<SIZE>, InsufficientCapacity> {
+        if self.is_truncated() {
+            Err(InsufficientCapacity::new(
+                operation,
+                self.required,
+                SIZE,
+                self.len,
+            ))
+        } else {
+            Ok(self.finish())
+        }
+    }
+}
+
+impl<const SIZE: usize> Default for RocStrBuilder<SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, const SIZE: usize> Extend<&'a str> for RocStrBuilder<SIZE> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push_str(value));
+    }
+}
+
+impl<const SIZE: usize> Extend<char> for RocStrBuilder<SIZE> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+/// Collects `&str` pieces, see [`RocStrBuilder::try_finish`] to fail if they do not fit.
+///
+/// # Examples
+/// ```
+/// # use rocstr::rocbuilder::RocStrBuilder;
+/// let builder: RocStrBuilder<16> = ["usr", "/", "local"].into_iter().collect();
+/// assert_eq!(builder.try_finish().unwrap(), "usr/local");
+/// ```
+impl<'a, const SIZE: usize> FromIterator<&'a str> for RocStrBuilder<SIZE> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
+
+/// Collects chars, see [`RocStrBuilder::try_finish`] to fail if they do not fit.
+impl<const SIZE: usize> FromIterator<char> for RocStrBuilder<SIZE> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
+
+/// Writing never fails : what does not fit in the capacity is dropped and tracked.
+impl<const SIZE: usize> Write for RocStrBuilder<SIZE> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+/// Creates a [`RocStr`] using interpolation of runtime expressions, like `format!`, without allocation.
+///
+/// The first argument is the capacity, followed by a `;` and the `format!` arguments.
+/// The result is silently trimmed to at most the capacity.
+///
+/// # Panics
+///
+/// Panics if a formatting trait implementation returns an error, like `format!`.
+///
+/// # Examples
+/// ```
+/// # use rocstr::rocformat;
+/// let code = rocformat!(16; "{}-{:02}-{:03}", "FR", 75, 1);
+/// assert_eq!(code, "FR-75-001");
+///
+/// let code = rocformat!(4; "{}-{:02}-{:03}", "FR", 75, 1);
+/// assert_eq!(code, "FR-7");
+/// ```
+#[macro_export]
+macro_rules! rocformat {
+    ($size:expr; $($arg:tt)*) => {{
+        let mut builder = $crate::rocbuilder::RocStrBuilder::<$size>::new();
+        ::core::fmt::Write::write_fmt(&mut builder, ::core::format_args!($($arg)*))
+            .expect("a formatting trait implementation returned an error");
+        builder.finish()
+    }};
+}
+
+/// Creates a [`RocStr`] using interpolation of runtime expressions, like `format!`, without allocation.
+///
+/// The first argument is the capacity, followed by a `;` and the `format!` arguments.
+/// It returns an [`InsufficientCapacity`] error if the result does not fit in the capacity.
+///
+/// # Panics
+///
+/// Panics if a formatting trait implementation returns an error, like `format!`.
+///
+/// # Examples
+/// ```
+/// # use rocstr::try_rocformat;
+/// let code = try_rocformat!(16; "{}-{:02}-{:03}", "FR", 75, 1);
+/// assert_eq!(code.unwrap(), "FR-75-001");
+///
+/// let code = try_rocformat!(4; "{}-{:02}-{:03}", "FR", 75, 1);
+/// assert_eq!(code.unwrap_err().required, 9);
+/// ```
+#[macro_export]
+macro_rules! try_rocformat {
+    ($size:expr; $($arg:tt)*) => {{
+        let mut builder = $crate::rocbuilder::RocStrBuilder::<$size>::new();
+        ::core::fmt::Write::write_fmt(&mut builder, ::core::format_args!($($arg)*))
+            .expect("a formatting trait implementation returned an error");
+        builder.try_finish()
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_builder_should_be_empty() {
+        let builder = RocStrBuilder::<16>::new();
+
+        assert!(builder.is_empty());
+        assert_eq!(builder.finish(), "");
+    }
+
+    #[test]
+    fn builder_with_enough_capacity_should_finish_into_the_written_str() {
+        let mut builder = RocStrBuilder::<16>::new();
+        builder.push_str("Löwe");
+        builder.push(' ');
+        builder.push('老');
+
+        assert!(!builder.is_truncated());
+        assert_eq!(builder.as_str(), "Löwe 老");
+        assert_eq!(builder.try_finish(), Ok(RocStr::from("Löwe 老")));
+    }
+
+    #[test]
+    fn builder_without_enough_capacity_should_be_truncated_at_utf8_boundary() {
+        let mut builder = RocStrBuilder::<8>::new();
+        builder.push_str("Löwe ");
+        builder.push_str("老虎");
+        builder.push_str("!");
+
+        assert!(builder.is_truncated());
+        assert_eq!(builder.len(), 6);
+        assert_eq!(builder.required(), 13);
+        assert_eq!(builder.dropped(), 7);
+        assert_eq!(builder.overflowing_finish(), (RocStr::from("Löwe "), 7));
+    }
+
+    #[test]
+    fn builder_without_enough_capacity_should_fail_to_finish() {
+        let mut builder = RocStrBuilder::<8>::new();
+        builder.push_str("Löwe 老虎");
+
+        assert_eq!(
+            builder.try_finish(),
+            Err(InsufficientCapacity::new(Operation::Format, 12, 8, 6))
+        );
+    }
+
+    #[test]
+    fn builder_should_extend_with_str_and_chars() {
+        let mut builder = RocStrBuilder::<16>::new();
+        builder.extend(["Löwe", " "]);
+        builder.extend("老虎".chars());
+
+        assert_eq!(builder.finish(), "Löwe 老虎");
+    }
+
+    #[test]
+    fn collected_builder_without_enough_capacity_should_fail_to_finish() {
+        let builder: RocStrBuilder<8> = ["Löwe", " ", "老虎"].into_iter().collect();
+        assert_eq!(
+            builder.try_finish(),
+            Err(InsufficientCapacity::new(Operation::Format, 12, 8, 6))
+        );
+
+        let builder: RocStrBuilder<8> = "Löwe 老虎".chars().collect();
+        assert_eq!(builder.required(), 12);
+    }
+
+    #[test]
+    fn rocformat_should_format_the_arguments() {
+        let value = 42;
+        let s = rocformat!(16; "{value:>5}|{:.1}|{:?}", 1.25, "a");
+
+        assert_eq!(s, "   42|1.2|\"a\"");
+        assert_eq!(s.capacity(), 16);
+    }
+
+    #[test]
+    fn rocformat_without_enough_capacity_should_be_truncated() {
+        let s = rocformat!(8; "{} {}", "Löwe", "老虎");
+        assert_eq!(s, "Löwe ");
+    }
+
+    #[test]
+    fn try_rocformat_without_enough_capacity_should_fail() {
+        let s = try_rocformat!(8; "{} {}", "Löwe", "老虎");
+        assert_eq!(
+            s,
+            Err(InsufficientCapacity::new(Operation::Format, 12, 8, 6))
+        );
+    }
+}
@@ -3,44 +3,191 @@
 use core::fmt::Display;
 use core::fmt::Formatter;
 use core::fmt::Result;
+use core::str::Utf8Error;
 
-use crate::RocStr;
+/// The [`RocStr`](crate::RocStr) operation which failed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    /// Building a [`RocStr`](crate::RocStr) from a `&str`.
+    FromStr,
+    /// Building a [`RocStr`](crate::RocStr) from a slice of bytes.
+    FromBytes,
+    /// Writing an integer into a [`RocStr`](crate::RocStr).
+    FromInt,
+    /// Changing the capacity of a [`RocStr`](crate::RocStr).
+    Reshape,
+    /// Truncating a [`RocStr`](crate::RocStr) to a given length.
+    Truncate,
+    /// Appending a string to a [`RocStr`](crate::RocStr).
+    Add,
+    /// Concatenating several strings into a [`RocStr`](crate::RocStr).
+    Concat,
+    /// Joining several strings with a separator into a [`RocStr`](crate::RocStr).
+    Join,
+    /// Replacing a pattern in a [`RocStr`](crate::RocStr).
+    Replace,
+    /// Writing into a [`RocStrBuilder`](crate::rocbuilder::RocStrBuilder), or formatting with `try_rocformat!`.
+    Format,
+    /// Converting a [`RocStr`](crate::RocStr) to uppercase.
+    ToUppercase,
+    /// Converting a [`RocStr`](crate::RocStr) to lowercase.
+    ToLowercase,
+    /// Converting a string to an identifier case, with the [`case`](crate::case) module.
+    ToCase,
+    /// Padding a [`RocStr`](crate::RocStr) to a given width.
+    Pad,
+}
 
-const DEFAULT_MESSAGE: &str = "CAPACITY ERROR : this RocStr cannot contains this string.";
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let name = match self {
+            Self::FromStr => "from_str",
+            Self::FromBytes => "from_bytes",
+            Self::FromInt => "from_int",
+            Self::Reshape => "reshape",
+            Self::Truncate => "truncate",
+            Self::Add => "add",
+            Self::Concat => "concat",
+            Self::Join => "join",
+            Self::Replace => "replace",
+            Self::Format => "format",
+            Self::ToUppercase => "to_uppercase",
+            Self::ToLowercase => "to_lowercase",
+            Self::ToCase => "to_case",
+            Self::Pad => "pad",
+        };
+        f.write_str(name)
+    }
+}
 
+/// Error returned when a string does not fit in the capacity of a [`RocStr`](crate::RocStr).
+///
+/// It does not depend on the capacity of the [`RocStr`](crate::RocStr),
+/// so errors from [`RocStr`](crate::RocStr) of different sizes share the same type.
+///
+/// # Examples
+/// ```
+/// # use rocstr::rocerr::Operation;
+/// # use rocstr::RocStr;
+/// let error = RocStr::<7>::try_from_str("Löwe 老虎").unwrap_err();
+///
+/// assert_eq!(error.operation, Operation::FromStr);
+/// assert_eq!(error.required, 12);
+/// assert_eq!(error.capacity, 7);
+/// /* second byte of `老`is not utf-8 boundary */
+/// assert_eq!(error.truncated_at, 6);
+/// ```
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct InsufficientCapacity<const SIZE: usize>(RocStr<SIZE>);
+#[non_exhaustive]
+pub struct InsufficientCapacity {
+    /// The operation which failed.
+    pub operation: Operation,
+    /// The number of bytes the result would need.
+    pub required: usize,
+    /// The number of bytes available.
+    pub capacity: usize,
+    /// The byte offset, on a utf-8 boundary, where the result would be truncated.
+    pub truncated_at: usize,
+}
 
-impl<const SIZE: usize> From<RocStr<SIZE>> for InsufficientCapacity<SIZE> {
+impl InsufficientCapacity {
+    /// Creates a new insufficient capacity error.
     #[inline]
     #[must_use]
-    fn from(value: RocStr<SIZE>) -> Self {
-        Self(value)
+    pub const fn new(
+        operation: Operation,
+        required: usize,
+        capacity: usize,
+        truncated_at: usize,
+    ) -> Self {
+        Self {
+            operation,
+            required,
+            capacity,
+            truncated_at,
+        }
     }
 }
 
-impl<const SIZE: usize, T> From<T> for InsufficientCapacity<SIZE>
-where
-    T: AsRef<str>,
-{
+impl Display for InsufficientCapacity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "CAPACITY ERROR : `{}` requires {} bytes but the capacity is {} bytes, it would be truncated at byte {}.",
+            self.operation, self.required, self.capacity, self.truncated_at
+        )
+    }
+}
+
+/// Error returned when building a [`RocStr`](crate::RocStr) from a slice of bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FromBytesError {
+    /// The bytes are not a valid utf-8 string.
+    Utf8(Utf8Error),
+    /// The bytes do not fit in the [`RocStr`](crate::RocStr) capacity.
+    InsufficientCapacity(InsufficientCapacity),
+}
+
+impl From<Utf8Error> for FromBytesError {
     #[inline]
-    #[must_use]
-    fn from(value: T) -> Self {
-        Self(RocStr::from(value.as_ref()))
+    fn from(value: Utf8Error) -> Self {
+        Self::Utf8(value)
+    }
+}
+
+impl From<InsufficientCapacity> for FromBytesError {
+    #[inline]
+    fn from(value: InsufficientCapacity) -> Self {
+        Self::InsufficientCapacity(value)
     }
 }
 
-impl<const SIZE: usize> Display for InsufficientCapacity<SIZE> {
+impl Display for FromBytesError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.0)
+        match self {
+            Self::Utf8(e) => write!(f, "{e}"),
+            Self::InsufficientCapacity(e) => write!(f, "{e}"),
+        }
     }
 }
 
-impl Default for InsufficientCapacity<57> {
+/// Error returned when a [`RocStr`](crate::RocStr) is not split in the expected number of pieces,
+/// with [`RocStr::split_array`](crate::RocStr::split_array).
+///
+/// # Examples
+/// ```
+/// # use rocstr::RocStr;
+/// let error = RocStr::<16>::from("FR-75").split_array::<3>("-").unwrap_err();
+///
+/// assert_eq!(error.expected, 3);
+/// assert_eq!(error.found, 2);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PieceCountError {
+    /// The expected number of pieces.
+    pub expected: usize,
+    /// The number of pieces found.
+    pub found: usize,
+}
+
+impl PieceCountError {
+    /// Creates a new piece count error.
     #[inline]
     #[must_use]
-    fn default() -> Self {
-        DEFAULT_MESSAGE.into()
+    pub const fn new(expected: usize, found: usize) -> Self {
+        Self { expected, found }
+    }
+}
+
+impl Display for PieceCountError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "PIECE COUNT ERROR : expected {} pieces but found {}.",
+            self.expected, self.found
+        )
     }
 }
 
@@ -48,7 +195,9 @@ impl Default for InsufficientCapacity<57> {
 pub mod std {
     extern crate std;
     use super::*;
-    impl<const SIZE: usize> std::error::Error for InsufficientCapacity<SIZE> {}
+    impl std::error::Error for InsufficientCapacity {}
+    impl std::error::Error for FromBytesError {}
+    impl std::error::Error for PieceCountError {}
 }
 
 #[cfg(test)]
@@ -56,41 +205,48 @@ mod tests {
     use super::*;
 
     #[test]
-    fn default_rocerr_should_be_the_defined_default_value() {
-        let expected = InsufficientCapacity(RocStr::<57>::from(DEFAULT_MESSAGE));
-        let sample = InsufficientCapacity::default();
+    fn rocerr_new_should_contain_the_given_values() {
+        let sample = InsufficientCapacity::new(Operation::Add, 17, 16, 14);
 
-        assert_eq!(expected, sample);
+        assert_eq!(sample.operation, Operation::Add);
+        assert_eq!(sample.required, 17);
+        assert_eq!(sample.capacity, 16);
+        assert_eq!(sample.truncated_at, 14);
     }
 
     #[test]
-    fn rocerr_from_str_should_contains_str_message() {
-        let expected = InsufficientCapacity(RocStr::<25>::from("This is a capacity error."));
-        let sample = InsufficientCapacity::from("This is a capacity error.");
+    fn rocerr_should_display_the_operation_and_the_lengths() {
+        extern crate std;
+        use std::format;
 
-        assert_eq!(expected, sample);
+        let sample = format!("{}", InsufficientCapacity::new(Operation::Add, 17, 16, 14));
+
+        assert_eq!(
+            sample,
+            "CAPACITY ERROR : `add` requires 17 bytes but the capacity is 16 bytes, it would be truncated at byte 14."
+        );
     }
 
     #[test]
-    fn rocerr_from_rocstr_should_contains_rocstr_message() {
-        let expected = InsufficientCapacity(RocStr::<25>::from("This is a capacity error."));
-        let sample = InsufficientCapacity::from(RocStr::<25>::from("This is a capacity error."));
+    fn piece_count_error_should_display_the_counts() {
+        extern crate std;
+        use std::format;
 
-        assert_eq!(expected, sample);
+        let sample = format!("{}", PieceCountError::new(3, 2));
+
+        assert_eq!(sample, "PIECE COUNT ERROR : expected 3 pieces but found 2.");
     }
 
     #[test]
-    fn rocerr_should_display_as_a_str_message() {
-        extern crate std;
-        use std::format;
-        use std::string::ToString;
+    fn rocerr_from_different_capacities_should_share_the_same_type() {
+        use crate::RocStr;
 
-        let expected = "This is a capacity error.".to_string();
-        let sample = format!(
-            "{}",
-            InsufficientCapacity::<25>::from("This is a capacity error.")
-        );
+        let errors: [InsufficientCapacity; 2] = [
+            RocStr::<2>::try_from_str("foo").unwrap_err(),
+            RocStr::<4>::try_from_str("foo bar").unwrap_err(),
+        ];
 
-        assert_eq!(expected, sample);
+        assert_eq!(errors[0].capacity, 2);
+        assert_eq!(errors[1].capacity, 4);
     }
 }
@@ -0,0 +1,662 @@
+//! RocStr integer formatting : radix and digit grouping options for [`RocStr::from_int_with`]
+
+use core::ops::Div;
+use core::ops::Mul;
+use core::ops::Neg;
+use core::ops::Sub;
+
+use crate::rocbuilder::RocStrBuilder;
+#[cfg(doc)]
+use crate::RocStr;
+
+/// The base in which an integer is written, see [`RocStr::from_int_with`].
+///
+/// Digits above 9 are ASCII letters.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Radix {
+    /// Base 2, `0` and `1`.
+    Binary,
+    /// Base 8, `0` to `7`.
+    Octal,
+    /// Base 10, `0` to `9`.
+    Decimal,
+    /// Base 16, `0` to `9` then `a` to `f`.
+    LowerHex,
+    /// Base 16, `0` to `9` then `A` to `F`.
+    UpperHex,
+    /// Base 36, `0` to `9` then `a` to `z`.
+    Base36,
+}
+
+impl Radix {
+    /// Returns the base of this radix.
+    #[inline]
+    #[must_use]
+    pub const fn base(self) -> u8 {
+        match self {
+            Self::Binary => 2,
+            Self::Octal => 8,
+            Self::Decimal => 10,
+            Self::LowerHex | Self::UpperHex => 16,
+            Self::Base36 => 36,
+        }
+    }
+}
+
+/// How an integer is written, see [`RocStr::from_int_with`].
+///
+/// # Examples
+/// ```
+/// # use rocstr::rocint::IntFormat;
+/// # use rocstr::rocint::Radix;
+/// # use rocstr::RocStr;
+/// const AMOUNT: IntFormat = IntFormat::new(Radix::Decimal).with_separator(' ', 3);
+///
+/// assert_eq!(RocStr::<16>::from_int_with(-1234567, AMOUNT), "-1 234 567");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntFormat {
+    radix: Radix,
+    separator: Option<(char, usize)>,
+}
+
+impl IntFormat {
+    /// Creates a format in `radix`, without separator.
+    #[inline]
+    #[must_use]
+    pub const fn new(radix: Radix) -> Self {
+        Self {
+            radix,
+            separator: None,
+        }
+    }
+
+    /// Returns this format with `separator` between every `group` digits, counted from the last one.
+    ///
+    /// A `group` of zero means no separator.
+    #[inline]
+    #[must_use]
+    pub const fn with_separator(self, separator: char, group: usize) -> Self {
+        Self {
+            radix: self.radix,
+            separator: if group == 0 {
+                None
+            } else {
+                Some((separator, group))
+            },
+        }
+    }
+}
+
+impl From<Radix> for IntFormat {
+    #[inline]
+    fn from(radix: Radix) -> Self {
+        Self::new(radix)
+    }
+}
+
+impl Default for IntFormat {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Radix::Decimal)
+    }
+}
+
+/// An integer type which can be written into a [`RocStr`], with [`RocStr::from_int`].
+///
+/// It is implemented for every primitive integer type, and cannot be implemented outside this crate.
+pub trait Integer: private::Sealed {}
+
+mod private {
+    /// Enough room for the digits of the widest integer, `u128` in binary.
+    pub const DIGITS_CAPACITY: usize = 128;
+
+    pub trait Sealed: Copy {
+        /// Writes the digits of the absolute value of `self` at the end of `buffer`,
+        /// returning the offset of the first digit and whether `self` is negative.
+        fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool);
+    }
+}
+
+use private::Sealed;
+use private::DIGITS_CAPACITY;
+
+/// Writes `value` into `builder`, following `format`.
+pub(crate) fn write_int<const SIZE: usize, T: Integer>(
+    builder: &mut RocStrBuilder<SIZE>,
+    value: T,
+    format: IntFormat,
+) {
+    let mut buffer = [0; DIGITS_CAPACITY];
+    let (start, negative) = value.digits(format.radix.base(), &mut buffer);
+    if format.radix == Radix::UpperHex {
+        buffer[start..].make_ascii_uppercase();
+    }
+
+    if negative {
+        builder.push('-');
+    }
+    let digits = &buffer[start..];
+    for (i, digit) in digits.iter().enumerate() {
+        if let Some((separator, group)) = format.separator {
+            if i > 0 && (digits.len() - i) % group == 0 {
+                builder.push(separator);
+            }
+        }
+        builder.push(char::from(*digit));
+    }
+}
+
+trait Zero {
+    fn zero() -> Self;
+}
+
+trait FromBase {
+    fn from_base(base: u8) -> Self;
+}
+
+trait AsDigit {
+    fn as_digit(&self) -> u8;
+}
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+fn next_char<T>(value: T, base: T) -> (T, u8)
+where
+    T: Copy + Div<Output = T> + Mul<Output = T> + Sub<Output = T> + AsDigit,
+{
+    let next = value / base;
+    let mask = next * base;
+    let digit = (value - mask).as_digit();
+
+    (next, DIGITS[digit as usize])
+}
+
+fn unsigned_digits<T>(value: T, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> usize
+where
+    T: Copy + Ord + Div<Output = T> + Mul<Output = T> + Sub<Output = T> + Zero + FromBase + AsDigit,
+{
+    let base = T::from_base(base);
+    let mut value = value;
+    let mut start = DIGITS_CAPACITY;
+    loop {
+        start -= 1;
+        let (next, char) = next_char(value, base);
+        buffer[start] = char;
+        value = next;
+        if value == T::zero() {
+            return start;
+        }
+    }
+}
+
+fn signed_digits<T>(value: T, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool)
+where
+    T: Copy
+        + Ord
+        + Neg<Output = T>
+        + Div<Output = T>
+        + Mul<Output = T>
+        + Sub<Output = T>
+        + Zero
+        + FromBase
+        + AsDigit,
+{
+    if value >= T::zero() {
+        return (unsigned_digits(value, base, buffer), false);
+    }
+
+    // The absolute value of `MIN` overflows, so the last digit is computed on the negative value
+    let next = value / T::from_base(base);
+    let last = -(value - next * T::from_base(base));
+    let last = DIGITS[last.as_digit() as usize];
+    if next == T::zero() {
+        buffer[DIGITS_CAPACITY - 1] = last;
+        (DIGITS_CAPACITY - 1, true)
+    } else {
+        let start = unsigned_digits(-next, base, buffer) - 1;
+        buffer.copy_within(start + 1.., start);
+        buffer[DIGITS_CAPACITY - 1] = last;
+        (start, true)
+    }
+}
+
+impl Integer for u8 {}
+impl Integer for u16 {}
+impl Integer for u32 {}
+impl Integer for u64 {}
+impl Integer for u128 {}
+impl Integer for usize {}
+impl Integer for i8 {}
+impl Integer for i16 {}
+impl Integer for i32 {}
+impl Integer for i64 {}
+impl Integer for i128 {}
+impl Integer for isize {}
+
+impl Sealed for u8 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        (unsigned_digits(self, base, buffer), false)
+    }
+}
+
+impl Sealed for u16 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        (unsigned_digits(self, base, buffer), false)
+    }
+}
+
+impl Sealed for u32 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        (unsigned_digits(self, base, buffer), false)
+    }
+}
+
+impl Sealed for u64 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        (unsigned_digits(self, base, buffer), false)
+    }
+}
+
+impl Sealed for u128 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        (unsigned_digits(self, base, buffer), false)
+    }
+}
+
+impl Sealed for usize {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        (unsigned_digits(self, base, buffer), false)
+    }
+}
+
+impl Sealed for i8 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        signed_digits(self, base, buffer)
+    }
+}
+
+impl Sealed for i16 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        signed_digits(self, base, buffer)
+    }
+}
+
+impl Sealed for i32 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        signed_digits(self, base, buffer)
+    }
+}
+
+impl Sealed for i64 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        signed_digits(self, base, buffer)
+    }
+}
+
+impl Sealed for i128 {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        signed_digits(self, base, buffer)
+    }
+}
+
+impl Sealed for isize {
+    fn digits(self, base: u8, buffer: &mut [u8; DIGITS_CAPACITY]) -> (usize, bool) {
+        signed_digits(self, base, buffer)
+    }
+}
+
+impl Zero for u8 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for u16 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for u32 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for u64 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for u128 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for usize {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for i8 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for i16 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for i32 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for i64 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for i128 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl Zero for isize {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl FromBase for u8 {
+    fn from_base(base: u8) -> Self {
+        base
+    }
+}
+
+impl FromBase for u16 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for u32 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for u64 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for u128 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for usize {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for i8 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for i16 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for i32 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for i64 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for i128 {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl FromBase for isize {
+    fn from_base(base: u8) -> Self {
+        base as Self
+    }
+}
+
+impl AsDigit for u8 {
+    fn as_digit(&self) -> u8 {
+        *self
+    }
+}
+
+impl AsDigit for u16 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for u32 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for u64 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for u128 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for usize {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for i8 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for i16 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for i32 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for i64 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for i128 {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl AsDigit for isize {
+    fn as_digit(&self) -> u8 {
+        *self as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::rocerr::InsufficientCapacity;
+    use crate::rocerr::Operation;
+    use crate::RocStr;
+
+    #[test]
+    fn from_int_of_zero_should_be_zero_in_every_radix() {
+        for radix in [
+            Radix::Binary,
+            Radix::Octal,
+            Radix::Decimal,
+            Radix::LowerHex,
+            Radix::UpperHex,
+            Radix::Base36,
+        ] {
+            assert_eq!(RocStr::<4>::from_int_with(0_u8, radix), "0");
+            assert_eq!(RocStr::<4>::from_int_with(0_i8, radix), "0");
+        }
+    }
+
+    #[test]
+    fn from_int_of_bounds_should_be_like_format() {
+        assert_eq!(RocStr::<40>::from_int(u128::MAX), format!("{}", u128::MAX));
+        assert_eq!(RocStr::<40>::from_int(i128::MIN), format!("{}", i128::MIN));
+        assert_eq!(RocStr::<40>::from_int(i128::MAX), format!("{}", i128::MAX));
+        assert_eq!(RocStr::<4>::from_int(i8::MIN), "-128");
+        assert_eq!(
+            RocStr::<129>::from_int_with(i128::MIN, Radix::Binary),
+            format!("-{:b}", i128::MIN.unsigned_abs())
+        );
+        assert_eq!(
+            RocStr::<128>::from_int_with(u128::MAX, Radix::Binary),
+            format!("{:b}", u128::MAX)
+        );
+        assert_eq!(
+            RocStr::<8>::from_int_with(i16::MIN, Radix::UpperHex),
+            "-8000"
+        );
+    }
+
+    #[test]
+    fn from_int_in_base36_should_use_every_letter() {
+        assert_eq!(RocStr::<8>::from_int_with(35_u8, Radix::Base36), "z");
+        assert_eq!(RocStr::<8>::from_int_with(36_u8, Radix::Base36), "10");
+        assert_eq!(
+            RocStr::<16>::from_int_with(i64::MIN, Radix::Base36),
+            "-1y2p0ij32e8e8"
+        );
+    }
+
+    #[test]
+    fn from_int_with_separator_should_group_digits_from_the_last_one() {
+        let thousands = IntFormat::new(Radix::Decimal).with_separator(',', 3);
+
+        assert_eq!(RocStr::<16>::from_int_with(1_u8, thousands), "1");
+        assert_eq!(RocStr::<16>::from_int_with(123_u8, thousands), "123");
+        assert_eq!(RocStr::<16>::from_int_with(-1234_i32, thousands), "-1,234");
+        assert_eq!(
+            RocStr::<16>::from_int_with(123456_u32, thousands),
+            "123,456"
+        );
+        assert_eq!(
+            RocStr::<32>::from_int_with(
+                i32::MIN,
+                IntFormat::new(Radix::Decimal).with_separator('’', 3)
+            ),
+            "-2’147’483’648"
+        );
+    }
+
+    #[test]
+    fn from_int_with_zero_group_should_not_have_separator() {
+        let format = IntFormat::new(Radix::Decimal).with_separator(',', 0);
+        assert_eq!(format, IntFormat::new(Radix::Decimal));
+        assert_eq!(RocStr::<16>::from_int_with(1234_u16, format), "1234");
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_int_without_enough_capacity_should_panic() {
+        let _ = RocStr::<3>::from_int(-1234_i16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_int_with_separators_without_enough_capacity_should_panic() {
+        let _ = RocStr::<8>::from_int_with(
+            1_234_567_u32,
+            IntFormat::new(Radix::Decimal).with_separator(',', 3),
+        );
+    }
+
+    #[test]
+    fn try_from_int_without_enough_capacity_should_fail() {
+        assert_eq!(
+            RocStr::<3>::try_from_int(-1234_i16),
+            Err(InsufficientCapacity::new(Operation::FromInt, 5, 3, 3))
+        );
+        assert_eq!(
+            RocStr::<8>::try_from_int_with(
+                1_234_567_u32,
+                IntFormat::new(Radix::Decimal).with_separator(',', 3)
+            ),
+            Err(InsufficientCapacity::new(Operation::FromInt, 9, 8, 8))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn from_int_should_be_like_format(value: i64) {
+            prop_assert_eq!(RocStr::<20>::from_int(value), format!("{value}"));
+        }
+
+        #[test]
+        fn from_int_with_radix_should_be_like_format(value: u128) {
+            prop_assert_eq!(RocStr::<128>::from_int_with(value, Radix::Binary), format!("{value:b}"));
+            prop_assert_eq!(RocStr::<64>::from_int_with(value, Radix::Octal), format!("{value:o}"));
+            prop_assert_eq!(RocStr::<32>::from_int_with(value, Radix::LowerHex), format!("{value:x}"));
+            prop_assert_eq!(RocStr::<32>::from_int_with(value, Radix::UpperHex), format!("{value:X}"));
+        }
+
+        #[test]
+        fn from_signed_int_with_radix_should_be_like_format_of_absolute_value(value: i32) {
+            let sign = if value < 0 { "-" } else { "" };
+            let abs = value.unsigned_abs();
+            prop_assert_eq!(RocStr::<33>::from_int_with(value, Radix::Binary), format!("{sign}{abs:b}"));
+            prop_assert_eq!(RocStr::<12>::from_int_with(value, Radix::Octal), format!("{sign}{abs:o}"));
+            prop_assert_eq!(RocStr::<9>::from_int_with(value, Radix::UpperHex), format!("{sign}{abs:X}"));
+        }
+    }
+}
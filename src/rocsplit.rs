@@ -0,0 +1,100 @@
+//! RocStr split iterators : iterators over the pieces of a [`RocStr`], yielding owned [`RocStr`]
+
+use core::iter::FusedIterator;
+
+use crate::RocStr;
+
+/// An iterator over the pieces of a [`RocStr`], each piece being an owned [`RocStr`] of the same capacity.
+///
+/// It wraps the matching `core::str` iterator,
+/// and is returned by [`RocStr::split`], [`RocStr::rsplit`], [`RocStr::splitn`],
+/// [`RocStr::split_whitespace`], [`RocStr::lines`] and [`RocStr::split_terminator`].
+///
+/// # Examples
+/// ```
+/// # use rocstr::RocStr;
+/// let code = RocStr::<16>::from("FR-75-001");
+/// let mut pieces = code.split("-");
+///
+/// assert_eq!(pieces.next(), Some(RocStr::from("FR")));
+/// assert_eq!(pieces.next(), Some(RocStr::from("75")));
+/// assert_eq!(pieces.next(), Some(RocStr::from("001")));
+/// assert_eq!(pieces.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Pieces<I, const SIZE: usize> {
+    inner: I,
+}
+
+impl<I, const SIZE: usize> Pieces<I, SIZE> {
+    pub(crate) fn new(inner: I) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, I, const SIZE: usize> Iterator for Pieces<I, SIZE>
+where
+    I: Iterator<Item = &'a str>,
+{
+    type Item = RocStr<SIZE>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(RocStr::from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, I, const SIZE: usize> DoubleEndedIterator for Pieces<I, SIZE>
+where
+    I: DoubleEndedIterator<Item = &'a str>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(RocStr::from)
+    }
+}
+
+impl<'a, I, const SIZE: usize> FusedIterator for Pieces<I, SIZE> where
+    I: FusedIterator<Item = &'a str>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pieces_should_yield_owned_rocstr() {
+        let pieces = {
+            let code = RocStr::<16>::from("FR-75-001");
+            let mut pieces = code.split("-");
+            [pieces.next(), pieces.next(), pieces.next(), pieces.next()]
+        };
+
+        assert_eq!(
+            pieces,
+            [
+                Some(RocStr::from("FR")),
+                Some(RocStr::from("75")),
+                Some(RocStr::from("001")),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn pieces_should_iterate_from_both_ends() {
+        let s = RocStr::<16>::from("FR 75 001");
+        let mut pieces = s.split_whitespace();
+
+        assert_eq!(pieces.next_back(), Some(RocStr::from("001")));
+        assert_eq!(pieces.next(), Some(RocStr::from("FR")));
+        assert_eq!(pieces.next_back(), Some(RocStr::from("75")));
+        assert_eq!(pieces.next(), None);
+    }
+}
@@ -1,25 +1,142 @@
 //! An immutable fixed capacity stack based generic copy string.
 
+use core::cmp::Ordering;
+use core::fmt::Alignment;
 use core::fmt::Debug;
 use core::fmt::Display;
 use core::fmt::Formatter;
 use core::fmt::Result;
 use core::hash::Hash;
 use core::hash::Hasher;
+use core::num::NonZeroU32;
 use core::ops::Add;
-use core::ops::Div;
-use core::ops::Mul;
-use core::ops::Neg;
-use core::ops::Sub;
+use core::ops::Bound;
+use core::ops::Index;
+use core::ops::RangeBounds;
+use core::slice::SliceIndex;
 use core::str::from_utf8;
-
-#[derive(Copy, Clone, Eq, PartialOrd, Ord)]
+use core::str::FromStr;
+use core::str::Lines;
+use core::str::Matches;
+use core::str::RSplit;
+use core::str::Split;
+use core::str::SplitN;
+use core::str::SplitTerminator;
+use core::str::SplitWhitespace;
+
+use crate::rocbuilder::RocStrBuilder;
+use crate::rocerr::FromBytesError;
+use crate::rocerr::InsufficientCapacity;
+use crate::rocerr::Operation;
+use crate::rocerr::PieceCountError;
+use crate::rocint::write_int;
+use crate::rocint::IntFormat;
+use crate::rocint::Integer;
+use crate::rocsplit::Pieces;
+
+/// An immutable fixed capacity stack based generic copy string.
+///
+/// # Invariant
+///
+/// Every byte of the backing array after the string length is zero,
+/// whatever the constructor or the operation the [`RocStr`] comes from.
+///
+/// Hence two [`RocStr`] with the same capacity are equal if and only if their backing arrays are equal,
+/// and the whole struct can be compared or serialized byte per byte.
+///
+/// # Layout
+///
+/// The length is stored, shifted by one, as a [`NonZeroU32`] rather than an `usize`,
+/// which is why the capacity is limited to `u32::MAX - 1`.
+/// On 64 bits targets, a `RocStr<8>` takes 12 bytes instead of 16.
+///
+/// As the zero value is never a valid length, the compiler uses it to represent `None`,
+/// so an `Option<RocStr<SIZE>>` takes no more space than a `RocStr<SIZE>`.
+#[derive(Copy, Clone, Eq)]
 pub struct RocStr<const SIZE: usize> {
     inner: [u8; SIZE],
-    len: usize,
+    len: NonZeroU32,
 }
 
 impl<const SIZE: usize> RocStr<SIZE> {
+    /// Builds a [`RocStr`] from its backing array and the length of the string it contains.
+    ///
+    /// `inner[..len]` must be a valid utf-8 string, and every byte after `len` must be zero.
+    #[inline]
+    #[must_use]
+    pub(crate) const fn from_parts(inner: [u8; SIZE], len: usize) -> Self {
+        assert!(
+            SIZE < u32::MAX as usize,
+            "RocStr capacity is limited to u32::MAX - 1"
+        );
+
+        // `len <= SIZE < u32::MAX`, so `len + 1` neither overflows nor is zero.
+        let len = match NonZeroU32::new(len as u32 + 1) {
+            Some(len) => len,
+            None => unreachable!(),
+        };
+
+        Self { inner, len }
+    }
+
+    /// Creates a [`RocStr`] from a `&str` in a const context.
+    ///
+    /// Prefer the [`rocstr!`](crate::rocstr!) macro for string literals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is longer than `SIZE`.
+    /// When evaluated in a `const` or a `static` item, it fails the compilation instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// const FRANCE: RocStr<2> = RocStr::from_str_const("FR");
+    /// static STATUSES: [RocStr<8>; 2] = [
+    ///     RocStr::from_str_const("pending"),
+    ///     RocStr::from_str_const("done"),
+    /// ];
+    ///
+    /// assert_eq!(FRANCE, "FR");
+    /// assert_eq!(STATUSES[1], "done");
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use rocstr::RocStr;
+    /// const FRANCE: RocStr<2> = RocStr::from_str_const("FRA");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_str_const(value: &str) -> Self {
+        let bytes = value.as_bytes();
+        assert!(
+            bytes.len() <= SIZE,
+            "the string does not fit in the RocStr capacity"
+        );
+
+        let mut inner = [0; SIZE];
+        let mut i = 0;
+        while i < bytes.len() {
+            inner[i] = bytes[i];
+            i += 1;
+        }
+
+        Self::from_parts(inner, bytes.len())
+    }
+
+    /// Creates a [`RocStr`] from a string literal of `LEN` bytes, used by the [`rocstr!`](crate::rocstr!) macro.
+    ///
+    /// `LEN` is checked against `SIZE` when the function is instantiated,
+    /// so an oversized literal fails the compilation even outside of a const context.
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    pub const fn from_literal<const LEN: usize>(value: &str) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = LiteralFits::<LEN, SIZE>::OK;
+        Self::from_str_const(value)
+    }
+
     /// Extracts a slice of bytes containing the entire [`RocStr`].
     ///
     /// # Examples
@@ -78,7 +195,7 @@ impl<const SIZE: usize> RocStr<SIZE> {
     #[inline]
     #[must_use]
     pub const fn is_empty(&self) -> bool {
-        self.len == 0
+        self.len() == 0
     }
 
     /// Returns the length of this [`RocStr`], in bytes, not [`char`]s or graphemes.
@@ -98,7 +215,7 @@ impl<const SIZE: usize> RocStr<SIZE> {
     #[inline]
     #[must_use]
     pub const fn len(&self) -> usize {
-        self.len
+        self.len.get() as usize - 1
     }
 
     /// Replaces all matches of a pattern with another string.
@@ -130,774 +247,2094 @@ impl<const SIZE: usize> RocStr<SIZE> {
     /// assert_eq!(s, s.replace("cookie monster", "little lamb"));
     /// ```
     pub fn replace(&self, from: &str, to: &str) -> Self {
+        self.replace_into(from, to).finish()
+    }
+
+    /// Replaces all matches of a pattern with another string, failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the replaced string is longer than `SIZE`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("this is old");
+    ///
+    /// assert_eq!(s.try_replace("old", "new").unwrap(), "this is new");
+    /// assert!(s.try_replace("old", "obviously overflowing").is_err());
+    /// ```
+    #[inline]
+    pub fn try_replace(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> core::result::Result<Self, InsufficientCapacity> {
+        self.replace_into(from, to)
+            .try_finish_with(Operation::Replace)
+    }
+
+    /// Replaces all matches of a pattern with another string,
+    /// returning the trimmed result along with the number of bytes that did not fit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("this is old");
+    ///
+    /// assert_eq!(s.overflowing_replace("old", "new"), (RocStr::from("this is new"), 0));
+    /// assert_eq!(
+    ///     s.overflowing_replace("old", "obviously overflowing"),
+    ///     (RocStr::from("this is obviousl"), 13)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn overflowing_replace(&self, from: &str, to: &str) -> (Self, usize) {
+        self.replace_into(from, to).overflowing_finish()
+    }
+
+    fn replace_into(&self, from: &str, to: &str) -> RocStrBuilder<SIZE> {
+        let mut builder = RocStrBuilder::new();
         if from.is_empty() {
-            *self
+            builder.push_str(self.as_str());
         } else {
-            let pattern = from.as_bytes();
-            let mut len = 0;
-            let mut skip = 0;
-
-            let mut inner = [b' '; SIZE];
-            let frames = self.inner[..self.len].windows(from.len()).enumerate();
-            for (i, frame) in frames {
-                if skip == 0 {
-                    // Nothing to skip
-                    if frame == pattern {
-                        let end = len + to.len();
-                        if end <= SIZE {
-                            inner[len..end].copy_from_slice(to.as_bytes());
-                            len += to.len();
-                            // skip the from.len() bytes minus the one we are in
-                            skip = from.len() - 1;
-                        } else {
-                            let remaining_slots = SIZE - len;
-                            inner[len..SIZE].copy_from_slice(&to.as_bytes()[0..remaining_slots]);
-                            len = SIZE;
-                            break;
-                        }
-                    } else if len < SIZE {
-                        inner[len] = self.inner[i];
-                        len += 1;
-                    } else {
-                        break;
-                    }
-                } else {
-                    skip -= 1;
-                    continue;
-                }
+            let mut pieces = self.as_str().split(from);
+            if let Some(piece) = pieces.next() {
+                builder.push_str(piece);
             }
-
-            // add the remaining bytes, the last frame, only if it remains some space
-            if len < SIZE && skip == 0 {
-                let remaining_slots = SIZE - len;
-                let remaining_bytes = &self.inner[self.len - from.len() + 1..self.len];
-                let remaining_bytes = if remaining_slots > remaining_bytes.len() {
-                    remaining_bytes
-                } else {
-                    &remaining_bytes[..remaining_slots]
-                };
-                inner[len..len + remaining_bytes.len()].copy_from_slice(remaining_bytes);
-                len += remaining_bytes.len();
+            for piece in pieces {
+                builder.push_str(to);
+                builder.push_str(piece);
             }
-
-            Self { inner, len }
         }
+
+        builder
     }
 
-    /// Returns a copy of this [`RocStr`] with capacity set to `LEN`.
+    /// Appends a `&str` to this [`RocStr`].
     ///
-    /// It will silently trim this [`RocStr`] if its length is greater than `LEN`.
+    /// It will silently trim the result to at most the capacity, like the `+` operator.
     ///
     /// # Examples
     /// ```
     /// # use rocstr::RocStr;
-    /// let s = RocStr::<16>::from("foo");
-    /// assert_eq!(s.reshape::<8>().capacity(), 8);
+    /// let s = RocStr::<8>::from("foo");
     ///
-    /// let s = RocStr::<16>::from("foo bar");
-    /// let t = RocStr::<4>::from("foo ");
-    /// assert_eq!(s.reshape::<4>(), t);
+    /// assert_eq!(s.saturating_add(" bar"), "foo bar");
+    /// assert_eq!(s.saturating_add(" bar baz"), "foo bar ");
     /// ```
     #[inline]
     #[must_use]
-    pub fn reshape<const LEN: usize>(&self) -> RocStr<LEN> {
-        let mut inner = [b' '; LEN];
-        let slice = extract_utf8_within(&self.inner[..self.len], LEN);
-        let len = slice.len();
-        inner[..len].copy_from_slice(slice);
-
-        RocStr { inner, len }
+    pub fn saturating_add(&self, rhs: &str) -> Self {
+        self.add_into(rhs).finish()
     }
 
-    /// Returns `true` if the given `&str` matches a prefix of this RocStr.
+    /// Appends a `&str` to this [`RocStr`], failing if the result overflows.
     ///
-    /// Returns `false` if it does not.
+    /// # Errors
     ///
-    /// # Examples
+    /// Returns [`InsufficientCapacity`] if the concatenation is longer than `SIZE`.
     ///
+    /// # Examples
     /// ```
     /// # use rocstr::RocStr;
-    /// let bananas = RocStr::<16>::from("bananas");
+    /// let s = RocStr::<8>::from("foo");
     ///
-    /// assert!(bananas.starts_with("bana"));
-    /// assert!(!bananas.starts_with("nana"));
+    /// assert_eq!(s.try_add(" bar").unwrap(), "foo bar");
+    /// assert!(s.try_add(" bar baz").is_err());
     /// ```
-    pub fn starts_with(&self, pattern: &str) -> bool {
-        self.as_bytes().starts_with(pattern.as_bytes())
+    #[inline]
+    pub fn try_add(&self, rhs: &str) -> core::result::Result<Self, InsufficientCapacity> {
+        self.add_into(rhs).try_finish_with(Operation::Add)
     }
 
-    /// Returns a [`RocStr`] with a valid utf-8 string with at most `len` bytes.
-    ///
-    /// The source [`RocStr`] remains unchanged.
+    /// Appends a `&str` to this [`RocStr`],
+    /// returning the trimmed result along with the number of bytes that did not fit.
     ///
     /// # Examples
-    ///
     /// ```
     /// # use rocstr::RocStr;
-    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
-    ///
-    /// /* first byte of `ö` is not utf-8 boundary */
-    /// assert_eq!(s.truncate(2), "L");
+    /// let s = RocStr::<8>::from("foo");
     ///
-    /// /* second byte of `老`is not utf-8 boundary */
-    /// assert_eq!(s.truncate(8), "Löwe ");
+    /// assert_eq!(s.overflowing_add(" bar"), (RocStr::from("foo bar"), 0));
+    /// assert_eq!(s.overflowing_add(" bar baz"), (RocStr::from("foo bar "), 3));
     /// ```
     #[inline]
     #[must_use]
-    pub fn truncate(&self, len: usize) -> Self {
-        let slice = extract_utf8_within(self.as_bytes(), len);
-        let len = slice.len();
-        let mut inner = [b' '; SIZE];
-        inner[..len].copy_from_slice(slice);
-
-        Self { inner, len }
+    pub fn overflowing_add(&self, rhs: &str) -> (Self, usize) {
+        self.add_into(rhs).overflowing_finish()
     }
-}
-
-impl<const SIZE: usize> Debug for RocStr<SIZE> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        let inner: &str = self.into();
-        f.debug_struct("RocStr")
-            .field("inner", &inner)
-            .field("len", &self.len)
-            .finish()
-    }
-}
 
-impl<const SIZE: usize> Default for RocStr<SIZE> {
-    fn default() -> Self {
-        Self {
-            inner: [0; SIZE],
-            len: Default::default(),
-        }
-    }
-}
+    fn add_into(&self, rhs: &str) -> RocStrBuilder<SIZE> {
+        let mut builder = RocStrBuilder::new();
+        builder.push_str(self.as_str());
+        builder.push_str(rhs);
 
-impl<const SIZE: usize> Display for RocStr<SIZE> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", Into::<&str>::into(self))
+        builder
     }
-}
 
-// Ideally, the signature should be
-//     `fn from(value: T) -> Self where T: AsRef<str>`
-// But this conflict with other `From`` implementation.
-impl<const SIZE: usize> From<&str> for RocStr<SIZE> {
+    /// Concatenates a slice of `&str` into a new [`RocStr`].
+    ///
+    /// It will silently trim the result to at most the capacity,
+    /// every piece after the first one overflowing is dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<16>::concat(&["foo", " ", "bar"]), "foo bar");
+    /// assert_eq!(RocStr::<4>::concat(&["foo", " ", "bar"]), "foo ");
+    /// ```
     #[inline]
     #[must_use]
-    fn from(value: &str) -> Self {
-        let bytes = value.as_bytes();
-        let slice = extract_utf8_within(bytes, SIZE);
-        let len = slice.len();
-
-        let mut inner = [0; SIZE];
-        inner[..len].copy_from_slice(slice);
-        Self { inner, len }
+    pub fn concat(pieces: &[&str]) -> Self {
+        Self::concat_into(pieces).finish()
     }
-}
 
-impl<'a, const SIZE: usize> From<&'a RocStr<SIZE>> for &'a str {
+    /// Concatenates a slice of `&str` into a new [`RocStr`], failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the concatenation is longer than `SIZE`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<16>::try_concat(&["foo", " ", "bar"]).unwrap(), "foo bar");
+    /// assert!(RocStr::<4>::try_concat(&["foo", " ", "bar"]).is_err());
+    /// ```
     #[inline]
-    #[must_use]
-    fn from(value: &'a RocStr<SIZE>) -> Self {
-        match from_utf8(value.inner[..value.len].as_ref()) {
-            Ok(string) => string,
-            // Unless unsafe use, this should never happen.
-            // This data is immutable and can only be initialized from a valid utf-8 string.
-            Err(_) => unreachable!(),
-        }
+    pub fn try_concat(pieces: &[&str]) -> core::result::Result<Self, InsufficientCapacity> {
+        Self::concat_into(pieces).try_finish_with(Operation::Concat)
     }
-}
 
-impl<'a, const SIZE: usize> From<&'a RocStr<SIZE>> for &'a [u8] {
+    /// Concatenates a slice of `&str` into a new [`RocStr`],
+    /// returning the trimmed result along with the number of bytes that did not fit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(
+    ///     RocStr::<4>::overflowing_concat(&["foo", " ", "bar"]),
+    ///     (RocStr::from("foo "), 3)
+    /// );
+    /// ```
     #[inline]
     #[must_use]
-    fn from(value: &'a RocStr<SIZE>) -> Self {
-        &value.inner[..value.len]
+    pub fn overflowing_concat(pieces: &[&str]) -> (Self, usize) {
+        Self::concat_into(pieces).overflowing_finish()
     }
-}
 
-impl<const SIZE: usize> Hash for RocStr<SIZE> {
-    #[inline]
-    fn hash<H: Hasher>(&self, hasher: &mut H) {
-        hasher.write(&self.inner[..self.len]);
-        hasher.write_u8(0xff);
-    }
-}
+    fn concat_into(pieces: &[&str]) -> RocStrBuilder<SIZE> {
+        let mut builder = RocStrBuilder::new();
+        for piece in pieces {
+            builder.push_str(piece);
+        }
 
-impl<const SIZE: usize> PartialEq<str> for RocStr<SIZE> {
-    #[inline]
-    #[must_use]
-    fn eq(&self, other: &str) -> bool {
-        self.len == other.len() && &self.inner[..self.len] == other.as_bytes()
+        builder
     }
-}
 
-impl<const SIZE: usize, T> PartialEq<T> for RocStr<SIZE>
-where
-    T: AsRef<str>,
-{
+    /// Joins the pieces of an iterator with a separator into a new [`RocStr`], in one pass.
+    ///
+    /// It will silently trim the result to at most the capacity.
+    /// [`RocStr`] pieces can be joined by mapping them with [`as_str`](Self::as_str).
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<16>::join("/", ["usr", "local", "bin"]), "usr/local/bin");
+    /// assert_eq!(RocStr::<8>::join("/", ["usr", "local", "bin"]), "usr/loca");
+    ///
+    /// let names = [RocStr::<8>::from("Alice"), RocStr::<8>::from("Bob")];
+    /// assert_eq!(RocStr::<16>::join(", ", names.iter().map(RocStr::as_str)), "Alice, Bob");
+    /// ```
     #[inline]
     #[must_use]
-    fn eq(&self, other: &T) -> bool {
-        let other = other.as_ref();
-        self.eq(other)
+    pub fn join<I, S>(separator: &str, pieces: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::join_into(separator, pieces).finish()
     }
-}
 
-impl<const SIZE: usize> PartialEq<RocStr<SIZE>> for &str {
+    /// Joins the pieces of an iterator with a separator into a new [`RocStr`], failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the result is longer than `SIZE`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<16>::try_join("/", ["usr", "bin"]).unwrap(), "usr/bin");
+    /// assert_eq!(RocStr::<4>::try_join("/", ["usr", "bin"]).unwrap_err().required, 7);
+    /// ```
+    #[inline]
+    pub fn try_join<I, S>(
+        separator: &str,
+        pieces: I,
+    ) -> core::result::Result<Self, InsufficientCapacity>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::join_into(separator, pieces).try_finish_with(Operation::Join)
+    }
+
+    /// Joins the pieces of an iterator with a separator into a new [`RocStr`],
+    /// returning the trimmed result along with the number of bytes that did not fit.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(
+    ///     RocStr::<4>::overflowing_join("/", ["usr", "bin"]),
+    ///     (RocStr::from("usr/"), 3)
+    /// );
+    /// ```
     #[inline]
     #[must_use]
-    fn eq(&self, other: &RocStr<SIZE>) -> bool {
-        self.len() == other.len && self.as_bytes() == &other.inner[..other.len]
+    pub fn overflowing_join<I, S>(separator: &str, pieces: I) -> (Self, usize)
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self::join_into(separator, pieces).overflowing_finish()
+    }
+
+    fn join_into<I, S>(separator: &str, pieces: I) -> RocStrBuilder<SIZE>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut builder = RocStrBuilder::new();
+        for (i, piece) in pieces.into_iter().enumerate() {
+            if i > 0 {
+                builder.push_str(separator);
+            }
+            builder.push_str(piece.as_ref());
+        }
+
+        builder
     }
-}
 
-impl<const SIZE: usize, const LEN: usize> PartialEq<RocStr<SIZE>> for RocStr<LEN> {
+    /// Returns a copy of this [`RocStr`] with capacity set to `LEN`.
+    ///
+    /// It will silently trim this [`RocStr`] if its length is greater than `LEN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("foo");
+    /// assert_eq!(s.reshape::<8>().capacity(), 8);
+    ///
+    /// let s = RocStr::<16>::from("foo bar");
+    /// let t = RocStr::<4>::from("foo ");
+    /// assert_eq!(s.reshape::<4>(), t);
+    /// ```
     #[inline]
     #[must_use]
-    fn eq(&self, other: &RocStr<SIZE>) -> bool {
-        self.len() == other.len && self.inner[..self.len] == other.inner[..other.len]
-    }
-}
-
-impl<const SIZE: usize, T> Add<T> for RocStr<SIZE>
-where
-    T: AsRef<str>,
-{
-    type Output = Self;
-
-    fn add(self, rhs: T) -> Self::Output {
-        let rhs = rhs.as_ref();
-        let mut inner = self.inner;
-        let (slice, len) = if self.len + rhs.len() > SIZE {
-            let available_len = SIZE - self.len;
-            let slice = extract_utf8_within(rhs.as_bytes(), available_len);
-            (slice, SIZE)
-        } else {
-            let len = self.len + rhs.len();
-            let slice = rhs.as_bytes();
-            (slice, len)
-        };
-
-        inner[self.len..len].copy_from_slice(slice);
+    pub fn reshape<const LEN: usize>(&self) -> RocStr<LEN> {
+        let mut inner = [0; LEN];
+        let slice = extract_utf8_within(&self.inner[..self.len()], LEN);
+        let len = slice.len();
+        inner[..len].copy_from_slice(slice);
 
-        Self { inner, len }
+        RocStr::from_parts(inner, len)
     }
-}
-
-impl<const SIZE: usize, const LEN: usize> Add<RocStr<LEN>> for RocStr<SIZE> {
-    type Output = Self;
 
-    fn add(self, rhs: RocStr<LEN>) -> Self::Output {
-        let mut inner = self.inner;
-        let (slice, len) = if self.len + rhs.len > SIZE {
-            let available_len = SIZE - self.len;
-            let slice = extract_utf8_within(&rhs.inner, available_len);
-            (slice, SIZE)
-        } else {
-            let len = self.len + rhs.len;
-            let slice = &rhs.inner[..rhs.len];
-            (slice, len)
+    /// Returns `true` if the given `&str` matches a prefix of this RocStr.
+    ///
+    /// Returns `false` if it does not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let bananas = RocStr::<16>::from("bananas");
+    ///
+    /// assert!(bananas.starts_with("bana"));
+    /// assert!(!bananas.starts_with("nana"));
+    /// ```
+    pub fn starts_with(&self, pattern: &str) -> bool {
+        self.as_bytes().starts_with(pattern.as_bytes())
+    }
+
+    /// Returns `true` if the given `&str` matches a suffix of this [`RocStr`].
+    ///
+    /// Returns `false` if it does not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let bananas = RocStr::<16>::from("bananas");
+    ///
+    /// assert!(bananas.ends_with("anas"));
+    /// assert!(!bananas.ends_with("nana"));
+    /// ```
+    pub fn ends_with(&self, pattern: &str) -> bool {
+        self.as_bytes().ends_with(pattern.as_bytes())
+    }
+
+    /// Returns `true` if the given `&str` matches a sub-slice of this [`RocStr`].
+    ///
+    /// Returns `false` if it does not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let bananas = RocStr::<16>::from("bananas");
+    ///
+    /// assert!(bananas.contains("nana"));
+    /// assert!(!bananas.contains("apples"));
+    /// ```
+    pub fn contains(&self, pattern: &str) -> bool {
+        self.as_str().contains(pattern)
+    }
+
+    /// Returns the byte index of the first match of the given `&str` in this [`RocStr`].
+    ///
+    /// Returns `None` if it does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+    ///
+    /// assert_eq!(s.find("L"), Some(0));
+    /// assert_eq!(s.find("老"), Some(6));
+    /// assert_eq!(s.find("Tiger"), None);
+    /// ```
+    pub fn find(&self, pattern: &str) -> Option<usize> {
+        self.as_str().find(pattern)
+    }
+
+    /// Returns the byte index of the last match of the given `&str` in this [`RocStr`].
+    ///
+    /// Returns `None` if it does not match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+    ///
+    /// assert_eq!(s.rfind("L"), Some(13));
+    /// assert_eq!(s.rfind("Tiger"), None);
+    /// ```
+    pub fn rfind(&self, pattern: &str) -> Option<usize> {
+        self.as_str().rfind(pattern)
+    }
+
+    /// Returns an iterator over the matches of the given `&str` in this [`RocStr`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("abcXXXabcYYYabc");
+    ///
+    /// assert_eq!(s.matches("abc").count(), 3);
+    /// ```
+    pub fn matches<'a, 'p>(&'a self, pattern: &'p str) -> Matches<'a, &'p str> {
+        self.as_str().matches(pattern)
+    }
+
+    /// Returns a [`RocStr`] with the given prefix removed.
+    ///
+    /// Returns `None` if this [`RocStr`] does not start with `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("foo:bar");
+    ///
+    /// assert_eq!(s.strip_prefix("foo:"), Some(RocStr::from("bar")));
+    /// assert_eq!(s.strip_prefix("bar"), None);
+    /// ```
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Self> {
+        self.as_str().strip_prefix(prefix).map(Self::from)
+    }
+
+    /// Returns a [`RocStr`] with the given suffix removed.
+    ///
+    /// Returns `None` if this [`RocStr`] does not end with `suffix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("foo:bar");
+    ///
+    /// assert_eq!(s.strip_suffix(":bar"), Some(RocStr::from("foo")));
+    /// assert_eq!(s.strip_suffix("foo"), None);
+    /// ```
+    pub fn strip_suffix(&self, suffix: &str) -> Option<Self> {
+        self.as_str().strip_suffix(suffix).map(Self::from)
+    }
+
+    /// Splits this [`RocStr`] on the first occurrence of the given delimiter,
+    /// returning the [`RocStr`] before and after it.
+    ///
+    /// Returns `None` if the delimiter is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("FR-75-001");
+    ///
+    /// assert_eq!(s.split_once("-"), Some((RocStr::from("FR"), RocStr::from("75-001"))));
+    /// assert_eq!(s.split_once(":"), None);
+    /// ```
+    pub fn split_once(&self, delimiter: &str) -> Option<(Self, Self)> {
+        self.as_str()
+            .split_once(delimiter)
+            .map(|(before, after)| (Self::from(before), Self::from(after)))
+    }
+
+    /// Splits this [`RocStr`] on the last occurrence of the given delimiter,
+    /// returning the [`RocStr`] before and after it.
+    ///
+    /// Returns `None` if the delimiter is not found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("FR-75-001");
+    ///
+    /// assert_eq!(s.rsplit_once("-"), Some((RocStr::from("FR-75"), RocStr::from("001"))));
+    /// assert_eq!(s.rsplit_once(":"), None);
+    /// ```
+    pub fn rsplit_once(&self, delimiter: &str) -> Option<(Self, Self)> {
+        self.as_str()
+            .rsplit_once(delimiter)
+            .map(|(before, after)| (Self::from(before), Self::from(after)))
+    }
+
+    /// Returns an iterator over the pieces of this [`RocStr`] separated by `separator`, like [`str::split`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("FR-75-001");
+    /// let pieces: [RocStr<16>; 3] = [RocStr::from("FR"), RocStr::from("75"), RocStr::from("001")];
+    ///
+    /// assert!(s.split("-").eq(pieces));
+    /// ```
+    #[inline]
+    pub fn split<'a, 'p>(&'a self, separator: &'p str) -> Pieces<Split<'a, &'p str>, SIZE> {
+        Pieces::new(self.as_str().split(separator))
+    }
+
+    /// Returns an iterator over the pieces of this [`RocStr`] separated by `separator`, in reverse order,
+    /// like [`str::rsplit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("FR-75-001");
+    /// let pieces: [RocStr<16>; 3] = [RocStr::from("001"), RocStr::from("75"), RocStr::from("FR")];
+    ///
+    /// assert!(s.rsplit("-").eq(pieces));
+    /// ```
+    #[inline]
+    pub fn rsplit<'a, 'p>(&'a self, separator: &'p str) -> Pieces<RSplit<'a, &'p str>, SIZE> {
+        Pieces::new(self.as_str().rsplit(separator))
+    }
+
+    /// Returns an iterator over at most `n` pieces of this [`RocStr`] separated by `separator`,
+    /// the last piece being the remainder, like [`str::splitn`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("FR-75-001");
+    /// let pieces: [RocStr<16>; 2] = [RocStr::from("FR"), RocStr::from("75-001")];
+    ///
+    /// assert!(s.splitn(2, "-").eq(pieces));
+    /// ```
+    #[inline]
+    pub fn splitn<'a, 'p>(
+        &'a self,
+        n: usize,
+        separator: &'p str,
+    ) -> Pieces<SplitN<'a, &'p str>, SIZE> {
+        Pieces::new(self.as_str().splitn(n, separator))
+    }
+
+    /// Returns an iterator over the pieces of this [`RocStr`] separated by any amount of whitespace,
+    /// like [`str::split_whitespace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from(" Löwe \t 老虎\n");
+    /// let pieces: [RocStr<16>; 2] = [RocStr::from("Löwe"), RocStr::from("老虎")];
+    ///
+    /// assert!(s.split_whitespace().eq(pieces));
+    /// ```
+    #[inline]
+    pub fn split_whitespace(&self) -> Pieces<SplitWhitespace<'_>, SIZE> {
+        Pieces::new(self.as_str().split_whitespace())
+    }
+
+    /// Returns an iterator over the lines of this [`RocStr`], like [`str::lines`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("Löwe\r\n老虎\n");
+    /// let lines: [RocStr<16>; 2] = [RocStr::from("Löwe"), RocStr::from("老虎")];
+    ///
+    /// assert!(s.lines().eq(lines));
+    /// ```
+    #[inline]
+    pub fn lines(&self) -> Pieces<Lines<'_>, SIZE> {
+        Pieces::new(self.as_str().lines())
+    }
+
+    /// Returns an iterator over the pieces of this [`RocStr`] terminated by `terminator`,
+    /// without a trailing empty piece, like [`str::split_terminator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("FR;75;001;");
+    /// let pieces: [RocStr<16>; 3] = [RocStr::from("FR"), RocStr::from("75"), RocStr::from("001")];
+    ///
+    /// assert!(s.split_terminator(";").eq(pieces));
+    /// ```
+    #[inline]
+    pub fn split_terminator<'a, 'p>(
+        &'a self,
+        terminator: &'p str,
+    ) -> Pieces<SplitTerminator<'a, &'p str>, SIZE> {
+        Pieces::new(self.as_str().split_terminator(terminator))
+    }
+
+    /// Splits this [`RocStr`] in exactly `N` pieces separated by `separator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PieceCountError`] if there are not exactly `N` pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("FR-75-001");
+    /// let [country, department, district] = s.split_array("-").unwrap();
+    ///
+    /// assert_eq!(country, "FR");
+    /// assert_eq!(department, "75");
+    /// assert_eq!(district, "001");
+    ///
+    /// assert!(s.split_array::<2>("-").is_err());
+    /// ```
+    pub fn split_array<const N: usize>(
+        &self,
+        separator: &str,
+    ) -> core::result::Result<[Self; N], PieceCountError> {
+        let mut pieces = [Self::default(); N];
+        let mut split = self.split(separator);
+        let mut found = 0;
+        for piece in pieces.iter_mut() {
+            match split.next() {
+                Some(value) => {
+                    *piece = value;
+                    found += 1;
+                }
+                None => return Err(PieceCountError::new(N, found)),
+            }
+        }
+
+        match split.count() {
+            0 => Ok(pieces),
+            remaining => Err(PieceCountError::new(N, N + remaining)),
+        }
+    }
+
+    /// Parses this [`RocStr`] into another type, like [`str::parse`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the [`FromStr`] implementation of `F` if this [`RocStr`] cannot be parsed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// # use std::net::Ipv4Addr;
+    /// let port = RocStr::<8>::from("8080");
+    /// assert_eq!(port.parse::<u16>(), Ok(8080));
+    ///
+    /// let enabled = RocStr::<8>::from("true");
+    /// assert_eq!(enabled.parse::<bool>(), Ok(true));
+    ///
+    /// let host = RocStr::<16>::from("127.0.0.1");
+    /// assert_eq!(host.parse::<Ipv4Addr>(), Ok(Ipv4Addr::LOCALHOST));
+    ///
+    /// assert!(RocStr::<8>::from("foo").parse::<u16>().is_err());
+    /// ```
+    #[inline]
+    pub fn parse<F: FromStr>(&self) -> core::result::Result<F, F::Err> {
+        self.as_str().parse()
+    }
+
+    /// Returns a [`RocStr`] with a valid utf-8 string with at most `len` bytes.
+    ///
+    /// The source [`RocStr`] remains unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+    ///
+    /// /* first byte of `ö` is not utf-8 boundary */
+    /// assert_eq!(s.truncate(2), "L");
+    ///
+    /// /* second byte of `老`is not utf-8 boundary */
+    /// assert_eq!(s.truncate(8), "Löwe ");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn truncate(&self, len: usize) -> Self {
+        let slice = extract_utf8_within(self.as_bytes(), len);
+        let len = slice.len();
+        let mut inner = [0; SIZE];
+        inner[..len].copy_from_slice(slice);
+
+        Self::from_parts(inner, len)
+    }
+
+    /// Returns a [`RocStr`] with the bytes of this [`RocStr`] within `range`.
+    ///
+    /// Returns `None` if `range` is out of bounds or does not lie on utf-8 boundaries, like [`str::get`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+    ///
+    /// assert_eq!(s.get(6..12), Some(RocStr::from("老虎")));
+    /// assert_eq!(s.get(..1), Some(RocStr::from("L")));
+    ///
+    /// /* second byte of `老`is not utf-8 boundary */
+    /// assert_eq!(s.get(6..8), None);
+    /// assert_eq!(s.get(..64), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get<R: RangeBounds<usize>>(&self, range: R) -> Option<Self> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        self.as_str().get(start..end).map(Self::from)
+    }
+
+    /// Returns a [`RocStr`] with a valid utf-8 string within the bytes of this [`RocStr`] within `range`.
+    ///
+    /// Unlike [`get`](Self::get), it never fails :
+    /// bounds beyond the length are clamped to the length,
+    /// and bounds falling inside a multi-byte character step back to its first byte, like [`truncate`](Self::truncate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+    ///
+    /// assert_eq!(s.slice(6..12), "老虎");
+    ///
+    /// /* second bytes of `老` and `虎` are not utf-8 boundaries */
+    /// assert_eq!(s.slice(7..11), "老");
+    ///
+    /// assert_eq!(s.slice(13..64), "Léopard");
+    /// assert_eq!(s.slice(64..), "");
+    /// ```
+    #[must_use]
+    pub fn slice<R: RangeBounds<usize>>(&self, range: R) -> Self {
+        let bytes = self.as_bytes();
+        let len = bytes.len();
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
         };
+        let start = extract_utf8_within(bytes, start).len();
+        let end = extract_utf8_within(bytes, end).len();
+
+        self.extract(start, end)
+    }
+
+    /// Returns a [`RocStr`] with the first `count` characters of this [`RocStr`].
+    ///
+    /// If `count` is greater than the number of characters, it is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+    ///
+    /// assert_eq!(s.take_chars(7), "Löwe 老虎");
+    /// assert_eq!(s.take_chars(64), "Löwe 老虎 Léopard");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn take_chars(&self, count: usize) -> Self {
+        self.truncate(self.char_offset(count))
+    }
+
+    /// Returns a [`RocStr`] without the first `count` characters of this [`RocStr`].
+    ///
+    /// If `count` is greater than the number of characters, it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+    ///
+    /// assert_eq!(s.skip_chars(8), "Léopard");
+    /// assert_eq!(s.skip_chars(64), "");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn skip_chars(&self, count: usize) -> Self {
+        self.slice(self.char_offset(count)..)
+    }
+
+    /// Returns the byte offset of the character at index `count`, or the length if there are fewer characters.
+    #[inline]
+    fn char_offset(&self, count: usize) -> usize {
+        self.as_str()
+            .char_indices()
+            .nth(count)
+            .map_or(self.len(), |(offset, _)| offset)
+    }
+
+    /// Returns a [`RocStr`] with leading and trailing whitespace removed.
+    ///
+    /// Whitespace is defined according to the terms of the Unicode Derived Core Property `White_Space`,
+    /// like [`str::trim`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("\u{3000} Löwe\t\n");
+    ///
+    /// assert_eq!(s.trim(), "Löwe");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim(&self) -> Self {
+        Self::from(self.as_str().trim())
+    }
+
+    /// Returns a [`RocStr`] with leading whitespace removed, like [`str::trim_start`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("  Löwe  ");
+    ///
+    /// assert_eq!(s.trim_start(), "Löwe  ");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_start(&self) -> Self {
+        Self::from(self.as_str().trim_start())
+    }
+
+    /// Returns a [`RocStr`] with trailing whitespace removed, like [`str::trim_end`].
+    ///
+    /// This is the way to strip the blank padding of a `char(n)` database column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("  Löwe  ");
+    ///
+    /// assert_eq!(s.trim_end(), "  Löwe");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_end(&self) -> Self {
+        Self::from(self.as_str().trim_end())
+    }
+
+    /// Returns a [`RocStr`] with all the prefixes and suffixes matching `pattern` repeatedly removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("--Löwe----");
+    ///
+    /// assert_eq!(s.trim_matches("--"), "Löwe");
+    /// assert_eq!(s.trim_matches("---"), "--Löwe-");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_matches(&self, pattern: &str) -> Self {
+        Self::from(
+            self.as_str()
+                .trim_start_matches(pattern)
+                .trim_end_matches(pattern),
+        )
+    }
+
+    /// Returns a [`RocStr`] with all the prefixes matching `pattern` repeatedly removed,
+    /// like [`str::trim_start_matches`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("00042");
+    ///
+    /// assert_eq!(s.trim_start_matches("0"), "42");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_start_matches(&self, pattern: &str) -> Self {
+        Self::from(self.as_str().trim_start_matches(pattern))
+    }
+
+    /// Returns a [`RocStr`] with all the suffixes matching `pattern` repeatedly removed,
+    /// like [`str::trim_end_matches`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("1.2500");
+    ///
+    /// assert_eq!(s.trim_end_matches("0"), "1.25");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_end_matches(&self, pattern: &str) -> Self {
+        Self::from(self.as_str().trim_end_matches(pattern))
+    }
+
+    /// Returns a [`RocStr`] with all the leading and trailing characters matching `predicate` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("123Löwe456");
+    ///
+    /// assert_eq!(s.trim_by(|c| c.is_ascii_digit()), "Löwe");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_by<F: FnMut(char) -> bool>(&self, predicate: F) -> Self {
+        Self::from(self.as_str().trim_matches(predicate))
+    }
+
+    /// Returns a [`RocStr`] with all the leading characters matching `predicate` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("123Löwe456");
+    ///
+    /// assert_eq!(s.trim_start_by(|c| c.is_ascii_digit()), "Löwe456");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_start_by<F: FnMut(char) -> bool>(&self, predicate: F) -> Self {
+        Self::from(self.as_str().trim_start_matches(predicate))
+    }
 
-        inner[self.len..len].copy_from_slice(slice);
+    /// Returns a [`RocStr`] with all the trailing characters matching `predicate` removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("123Löwe456");
+    ///
+    /// assert_eq!(s.trim_end_by(|c| c.is_ascii_digit()), "123Löwe");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn trim_end_by<F: FnMut(char) -> bool>(&self, predicate: F) -> Self {
+        Self::from(self.as_str().trim_end_matches(predicate))
+    }
 
-        Self { inner, len }
+    /// Returns a [`RocStr`] with leading and trailing ASCII whitespace removed.
+    ///
+    /// ASCII whitespace is defined like [`u8::is_ascii_whitespace`].
+    /// Unlike [`trim`](Self::trim), it can be used in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// const CODE: RocStr<8> = RocStr::from_str_const(" FR-75 \n").trim_ascii();
+    ///
+    /// assert_eq!(CODE, "FR-75");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn trim_ascii(&self) -> Self {
+        self.extract(self.ascii_start(), self.ascii_end())
     }
-}
 
-/// Extract a valid utf-8 string from this byte array with at most `len` bytes.
-///
-/// # Examples
-/// let s = "Löwe 老虎 Léopard";
-///
-/// /* first byte of `ö` is not utf-8 boundary */
-/// assert_eq!("L", extract_utf8_within(s.as_bytes(), 2));
-///
-/// /* second byte of `老`is not utf-8 boundary */
-/// assert_eq!("Löwe ", extract_utf8_within(s.as_bytes(), 8));
-#[inline]
-#[must_use]
-fn extract_utf8_within(bytes: &[u8], len: usize) -> &[u8] {
-    let bytes_len = bytes.len();
-    let boundary = if len < bytes_len {
-        // This is bit magic equivalent to: b < 128 || b >= 192
-        if (bytes[len] as i8) >= -0x40 {
-            len
-        } else {
-            let mut boundary = len - 1;
-            // This is bit magic equivalent to: b >= 128 && b <= 192
-            while (bytes[boundary] as i8) < -0x40 {
-                boundary -= 1;
+    /// Returns a [`RocStr`] with leading ASCII whitespace removed, in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// const CODE: RocStr<8> = RocStr::from_str_const(" FR-75 \n").trim_ascii_start();
+    ///
+    /// assert_eq!(CODE, "FR-75 \n");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn trim_ascii_start(&self) -> Self {
+        self.extract(self.ascii_start(), self.len())
+    }
+
+    /// Returns a [`RocStr`] with trailing ASCII whitespace removed, in const contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// const CODE: RocStr<8> = RocStr::from_str_const(" FR-75 \n").trim_ascii_end();
+    ///
+    /// assert_eq!(CODE, " FR-75");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn trim_ascii_end(&self) -> Self {
+        self.extract(0, self.ascii_end())
+    }
+
+    /// Returns the byte offset of the first non ASCII whitespace, or the length.
+    const fn ascii_start(&self) -> usize {
+        let len = self.len();
+        let mut start = 0;
+        while start < len && self.inner[start].is_ascii_whitespace() {
+            start += 1;
+        }
+        start
+    }
+
+    /// Returns the byte offset following the last non ASCII whitespace, or zero.
+    const fn ascii_end(&self) -> usize {
+        let mut end = self.len();
+        while end > 0 && self.inner[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+        end
+    }
+
+    /// Returns a [`RocStr`] with the bytes from `start` to `end`, which must be utf-8 boundaries.
+    ///
+    /// It is empty if `start` is not lower than `end`.
+    const fn extract(&self, start: usize, end: usize) -> Self {
+        let mut inner = [0; SIZE];
+        let mut i = start;
+        while i < end {
+            inner[i - start] = self.inner[i];
+            i += 1;
+        }
+
+        Self::from_parts(inner, end.saturating_sub(start))
+    }
+
+    /// Returns a copy of this [`RocStr`] with ASCII letters mapped to their uppercase equivalent.
+    ///
+    /// Non ASCII characters are unchanged, so the length never changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// const SKU: RocStr<16> = RocStr::from_str_const("sku-löwe-42").to_ascii_uppercase();
+    ///
+    /// assert_eq!(SKU, "SKU-LöWE-42");
+    /// ```
+    #[must_use]
+    pub const fn to_ascii_uppercase(&self) -> Self {
+        let mut inner = self.inner;
+        let mut i = 0;
+        while i < SIZE {
+            inner[i] = inner[i].to_ascii_uppercase();
+            i += 1;
+        }
+
+        Self::from_parts(inner, self.len())
+    }
+
+    /// Returns a copy of this [`RocStr`] with ASCII letters mapped to their lowercase equivalent.
+    ///
+    /// Non ASCII characters are unchanged, so the length never changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// const USERNAME: RocStr<16> = RocStr::from_str_const("Alice.ÉLOÏSE").to_ascii_lowercase();
+    ///
+    /// assert_eq!(USERNAME, "alice.ÉloÏse");
+    /// ```
+    #[must_use]
+    pub const fn to_ascii_lowercase(&self) -> Self {
+        let mut inner = self.inner;
+        let mut i = 0;
+        while i < SIZE {
+            inner[i] = inner[i].to_ascii_lowercase();
+            i += 1;
+        }
+
+        Self::from_parts(inner, self.len())
+    }
+
+    /// Returns the uppercase equivalent of this [`RocStr`].
+    ///
+    /// Each character is mapped like [`char::to_uppercase`], so the result may be longer than the source.
+    /// It will silently trim the result to at most the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("Straße");
+    /// assert_eq!(s.to_uppercase(), "STRASSE");
+    ///
+    /// /* `ŉ` is 2 bytes long, but `ʼN` is 3 bytes long */
+    /// let s = RocStr::<4>::from("ŉŉ");
+    /// assert_eq!(s.to_uppercase(), "ʼN");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_uppercase(&self) -> Self {
+        self.uppercase_into().finish()
+    }
+
+    /// Returns the uppercase equivalent of this [`RocStr`], failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the uppercase equivalent is longer than `SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<4>::from("ŉŉ");
+    ///
+    /// assert_eq!(s.try_to_uppercase().unwrap_err().required, 6);
+    /// assert_eq!(s.reshape::<6>().try_to_uppercase().unwrap(), "ʼNʼN");
+    /// ```
+    #[inline]
+    pub fn try_to_uppercase(&self) -> core::result::Result<Self, InsufficientCapacity> {
+        self.uppercase_into()
+            .try_finish_with(Operation::ToUppercase)
+    }
+
+    /// Returns the lowercase equivalent of this [`RocStr`].
+    ///
+    /// Each character is mapped like [`char::to_lowercase`], so the result may be longer than the source.
+    /// Like [`str::to_lowercase`], a word final `Σ` is mapped to `ς` instead of `σ`.
+    /// It will silently trim the result to at most the capacity.
+    ///
+    /// Without the Unicode tables of `std`, the case ignorable characters skipped around a final `Σ`
+    /// are limited to apostrophes, periods, colons, the soft hyphen and combining diacritical marks,
+    /// so a `Σ` next to other marks, format characters or modifiers may be mapped differently than `std`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("LÖWE 老虎");
+    /// assert_eq!(s.to_lowercase(), "löwe 老虎");
+    ///
+    /// let s = RocStr::<16>::from("ΟΔΟΣ Σ");
+    /// assert_eq!(s.to_lowercase(), "οδος σ");
+    ///
+    /// /* `İ` is 2 bytes long, but `i̇` is 3 bytes long */
+    /// let s = RocStr::<2>::from("İ");
+    /// assert_eq!(s.to_lowercase(), "i");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_lowercase(&self) -> Self {
+        self.lowercase_into().finish()
+    }
+
+    /// Returns the lowercase equivalent of this [`RocStr`], failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the lowercase equivalent is longer than `SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<2>::from("İ");
+    ///
+    /// assert_eq!(s.try_to_lowercase().unwrap_err().required, 3);
+    /// assert_eq!(RocStr::<16>::from("LÖWE").try_to_lowercase().unwrap(), "löwe");
+    /// ```
+    #[inline]
+    pub fn try_to_lowercase(&self) -> core::result::Result<Self, InsufficientCapacity> {
+        self.lowercase_into()
+            .try_finish_with(Operation::ToLowercase)
+    }
+
+    fn uppercase_into(&self) -> RocStrBuilder<SIZE> {
+        let mut builder = RocStrBuilder::new();
+        self.as_str()
+            .chars()
+            .flat_map(char::to_uppercase)
+            .for_each(|c| builder.push(c));
+
+        builder
+    }
+
+    fn lowercase_into(&self) -> RocStrBuilder<SIZE> {
+        let mut builder = RocStrBuilder::new();
+        let s = self.as_str();
+        for (i, c) in s.char_indices() {
+            if c == 'Σ' && is_final_sigma(s, i) {
+                builder.push('ς');
+            } else {
+                c.to_lowercase().for_each(|c| builder.push(c));
             }
-            boundary
         }
-    } else {
-        bytes_len
-    };
 
-    &bytes[..boundary]
-}
+        builder
+    }
+
+    /// Returns a [`RocStr`] padded on the left with `fill` up to `width`, so the content is right-aligned.
+    ///
+    /// If this [`RocStr`] is already at least `width` wide, it is returned unchanged.
+    /// It will silently trim the result to at most the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::{RocStr, Width};
+    /// let s = RocStr::<16>::from("Löwe");
+    ///
+    /// assert_eq!(s.pad_left(Width::Chars(6), '.'), "..Löwe");
+    /// assert_eq!(s.pad_left(Width::Bytes(6), '.'), ".Löwe");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn pad_left(&self, width: Width, fill: char) -> Self {
+        self.pad_into(width, fill, Alignment::Right).finish()
+    }
+
+    /// Returns a [`RocStr`] padded on the left with `fill` up to `width`, failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the padded string is longer than `SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::{RocStr, Width};
+    /// let s = RocStr::<8>::from("42");
+    ///
+    /// assert_eq!(s.try_pad_left(Width::Chars(8), ' ').unwrap(), "      42");
+    /// assert!(s.try_pad_left(Width::Chars(10), ' ').is_err());
+    /// ```
+    #[inline]
+    pub fn try_pad_left(
+        &self,
+        width: Width,
+        fill: char,
+    ) -> core::result::Result<Self, InsufficientCapacity> {
+        self.pad_into(width, fill, Alignment::Right)
+            .try_finish_with(Operation::Pad)
+    }
+
+    /// Returns a [`RocStr`] padded on the right with `fill` up to `width`, so the content is left-aligned.
+    ///
+    /// If this [`RocStr`] is already at least `width` wide, it is returned unchanged.
+    /// It will silently trim the result to at most the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::{RocStr, Width};
+    /// let s = RocStr::<16>::from("Löwe");
+    ///
+    /// assert_eq!(s.pad_right(Width::Chars(6), ' '), "Löwe  ");
+    /// assert_eq!(s.pad_right(Width::Bytes(6), ' '), "Löwe ");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn pad_right(&self, width: Width, fill: char) -> Self {
+        self.pad_into(width, fill, Alignment::Left).finish()
+    }
+
+    /// Returns a [`RocStr`] padded on the right with `fill` up to `width`, failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the padded string is longer than `SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::{RocStr, Width};
+    /// let s = RocStr::<8>::from("FR");
+    ///
+    /// assert_eq!(s.try_pad_right(Width::Chars(8), ' ').unwrap(), "FR      ");
+    /// assert!(s.try_pad_right(Width::Chars(10), ' ').is_err());
+    /// ```
+    #[inline]
+    pub fn try_pad_right(
+        &self,
+        width: Width,
+        fill: char,
+    ) -> core::result::Result<Self, InsufficientCapacity> {
+        self.pad_into(width, fill, Alignment::Left)
+            .try_finish_with(Operation::Pad)
+    }
+
+    /// Returns a [`RocStr`] padded on both sides with `fill` up to `width`, so the content is centered.
+    ///
+    /// Like `format!("{:^}")`, the extra fill goes on the right when the padding is odd.
+    /// If this [`RocStr`] is already at least `width` wide, it is returned unchanged.
+    /// It will silently trim the result to at most the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::{RocStr, Width};
+    /// let s = RocStr::<16>::from("Löwe");
+    ///
+    /// assert_eq!(s.center(Width::Chars(7), '*'), "*Löwe**");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn center(&self, width: Width, fill: char) -> Self {
+        self.pad_into(width, fill, Alignment::Center).finish()
+    }
+
+    /// Returns a [`RocStr`] padded on both sides with `fill` up to `width`, failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the padded string is longer than `SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::{RocStr, Width};
+    /// let s = RocStr::<8>::from("FR");
+    ///
+    /// assert_eq!(s.try_center(Width::Chars(6), '-').unwrap(), "--FR--");
+    /// assert!(s.try_center(Width::Chars(10), '-').is_err());
+    /// ```
+    #[inline]
+    pub fn try_center(
+        &self,
+        width: Width,
+        fill: char,
+    ) -> core::result::Result<Self, InsufficientCapacity> {
+        self.pad_into(width, fill, Alignment::Center)
+            .try_finish_with(Operation::Pad)
+    }
+
+    /// Returns a [`RocStr`] padded on the left with `0` up to `width` chars,
+    /// keeping a leading `-` or `+` sign in front.
+    ///
+    /// If this [`RocStr`] is already at least `width` chars wide, it is returned unchanged.
+    /// It will silently trim the result to at most the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<8>::from("42").zfill(5), "00042");
+    /// assert_eq!(RocStr::<4>::from(-42_i8).reshape::<8>().zfill(5), "-0042");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn zfill(&self, width: usize) -> Self {
+        self.zfill_into(width).finish()
+    }
+
+    /// Returns a [`RocStr`] padded on the left with `0` up to `width` chars,
+    /// keeping a leading `-` or `+` sign in front, failing if the result overflows.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the padded string is longer than `SIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<4>::from("+7");
+    ///
+    /// assert_eq!(s.try_zfill(4).unwrap(), "+007");
+    /// assert!(s.try_zfill(5).is_err());
+    /// ```
+    #[inline]
+    pub fn try_zfill(&self, width: usize) -> core::result::Result<Self, InsufficientCapacity> {
+        self.zfill_into(width).try_finish_with(Operation::Pad)
+    }
+
+    /// Returns the number of `fill` needed to pad this [`RocStr`] up to `width`.
+    fn padding(&self, width: Width, fill: char) -> usize {
+        match width {
+            Width::Chars(width) => width.saturating_sub(self.as_str().chars().count()),
+            Width::Bytes(width) => width.saturating_sub(self.len()) / fill.len_utf8(),
+        }
+    }
+
+    fn pad_into(&self, width: Width, fill: char, alignment: Alignment) -> RocStrBuilder<SIZE> {
+        let padding = self.padding(width, fill);
+        let left = match alignment {
+            Alignment::Left => 0,
+            Alignment::Right => padding,
+            Alignment::Center => padding / 2,
+        };
 
-trait Zero {
-    fn zero() -> Self;
+        let mut builder = RocStrBuilder::new();
+        (0..left).for_each(|_| builder.push(fill));
+        builder.push_str(self.as_str());
+        (left..padding).for_each(|_| builder.push(fill));
 
-    fn zero_as_rocstr<const SIZE: usize>() -> RocStr<SIZE> {
-        let len = 1;
-        let mut inner = [b' '; SIZE];
-        inner[0] = b'0';
-        RocStr { inner, len }
+        builder
     }
-}
-
-trait Ten {
-    fn ten() -> Self;
-}
 
-trait AsDigit {
-    fn as_digit(&self) -> u8;
-}
-
-const ROCSTR_MIN_I8: RocStr<4> = RocStr {
-    inner: *b"-128",
-    len: 4,
-};
-const ROCSTR_MIN_I16: RocStr<6> = RocStr {
-    inner: *b"-32768",
-    len: 6,
-};
-const ROCSTR_MIN_I32: RocStr<11> = RocStr {
-    inner: *b"-2147483648",
-    len: 11,
-};
-const ROCSTR_MIN_I64: RocStr<20> = RocStr {
-    inner: *b"-9223372036854775808",
-    len: 20,
-};
-const ROCSTR_MIN_ISIZE: RocStr<20> = RocStr {
-    inner: *b"-9223372036854775808",
-    len: 20,
-};
-
-fn next_char<T>(value: T) -> (T, u8)
-where
-    T: Copy + Eq + Div<Output = T> + Mul<Output = T> + Sub<Output = T> + Zero + Ten + AsDigit,
-{
-    let next = value / T::ten();
-    let mask = next * T::ten();
-    let digit = (value - mask).as_digit();
-    let char = match digit {
-        0 => b'0',
-        1 => b'1',
-        2 => b'2',
-        3 => b'3',
-        4 => b'4',
-        5 => b'5',
-        6 => b'6',
-        7 => b'7',
-        8 => b'8',
-        9 => b'9',
-        // Unreachable beaucause digit is the remainder of the division by 10
-        _ => unreachable!(),
-    };
+    fn zfill_into(&self, width: usize) -> RocStrBuilder<SIZE> {
+        let padding = self.padding(Width::Chars(width), '0');
+        let value = self.as_str();
+        let (sign, digits) = match value.strip_prefix(['-', '+']) {
+            Some(digits) => value.split_at(value.len() - digits.len()),
+            None => ("", value),
+        };
 
-    (next, char)
-}
+        let mut builder = RocStrBuilder::new();
+        builder.push_str(sign);
+        (0..padding).for_each(|_| builder.push('0'));
+        builder.push_str(digits);
 
-fn from_signed<const SIZE: usize, T>(value: T) -> RocStr<SIZE>
-where
-    T: Copy
-        + Eq
-        + Neg<Output = T>
-        + Ord
-        + Div<Output = T>
-        + Mul<Output = T>
-        + Sub<Output = T>
-        + Zero
-        + Ten
-        + AsDigit,
-{
-    if value == T::zero() {
-        T::zero_as_rocstr()
-    } else {
-        let mut value = value;
-        let mut len = 0;
-        let mut buffer = [b' '; SIZE];
-
-        // Backup the sign
-        let negative = value < T::zero();
-        // Get absolute value
-        if negative {
-            value = -value;
-        }
+        builder
+    }
 
-        while value > T::zero() {
-            len += 1;
-            let (next, char) = next_char(value);
-            buffer[SIZE - len] = char;
-            value = next;
+    /// Creates a [`RocStr`] from a `&str`, failing if it does not fit in the capacity.
+    ///
+    /// Unlike `RocStr::from`, the string is never silently trimmed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if `value` is longer than `SIZE` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::try_from_str("foo");
+    /// assert_eq!(s.unwrap(), "foo");
+    ///
+    /// let s = RocStr::<2>::try_from_str("foo");
+    /// assert!(s.is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_str(value: &str) -> core::result::Result<Self, InsufficientCapacity> {
+        if value.len() > SIZE {
+            Err(insufficient_capacity(
+                Operation::FromStr,
+                value.as_bytes(),
+                SIZE,
+            ))
+        } else {
+            Ok(Self::from(value))
         }
+    }
 
-        // Add the sign at the beginning
-        if negative {
-            len += 1;
-            buffer[SIZE - len] = b'-';
+    /// Creates a [`RocStr`] from a slice of bytes, failing if they are not a valid utf-8 string
+    /// or if they do not fit in the capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromBytesError::Utf8`] if `bytes` is not a valid utf-8 string,
+    /// and [`FromBytesError::InsufficientCapacity`] if `bytes` is longer than `SIZE`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::try_from_bytes(b"foo");
+    /// assert_eq!(s.unwrap(), "foo");
+    ///
+    /// assert!(RocStr::<2>::try_from_bytes(b"foo").is_err());
+    /// assert!(RocStr::<16>::try_from_bytes(&[0xff, 0xfe]).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_bytes(bytes: &[u8]) -> core::result::Result<Self, FromBytesError> {
+        let value = from_utf8(bytes)?;
+        if value.len() > SIZE {
+            Err(insufficient_capacity(Operation::FromBytes, bytes, SIZE).into())
+        } else {
+            Ok(Self::from(value))
         }
+    }
 
-        let mut inner = [b' '; SIZE];
-        inner[..len].copy_from_slice(&buffer[SIZE - len..]);
+    /// Creates a [`RocStr`] from any integer, in decimal.
+    ///
+    /// Unlike strings, an integer is never trimmed, as a truncated number would be a different number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the integer is written with more than `SIZE` bytes,
+    /// use [`try_from_int`](Self::try_from_int) when the capacity may be too small.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<8>::from_int(42_u64), "42");
+    /// assert_eq!(RocStr::<8>::from_int(i8::MIN), "-128");
+    /// ```
+    ///
+    /// ```should_panic
+    /// # use rocstr::RocStr;
+    /// let _ = RocStr::<2>::from_int(-128_i16);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_int<T: Integer>(value: T) -> Self {
+        Self::from_int_with(value, IntFormat::default())
+    }
 
-        RocStr { inner, len }
+    /// Creates a [`RocStr`] from any integer, in decimal, failing if it does not fit in the capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the integer is written with more than `SIZE` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<4>::try_from_int(-128_i16).unwrap(), "-128");
+    /// assert_eq!(RocStr::<3>::try_from_int(-128_i16).unwrap_err().required, 4);
+    /// ```
+    #[inline]
+    pub fn try_from_int<T: Integer>(value: T) -> core::result::Result<Self, InsufficientCapacity> {
+        Self::try_from_int_with(value, IntFormat::default())
     }
-}
 
-fn from_unsigned<const SIZE: usize, T>(value: T) -> RocStr<SIZE>
-where
-    T: Copy + Eq + Ord + Div<Output = T> + Mul<Output = T> + Sub<Output = T> + Zero + Ten + AsDigit,
-{
-    if value == T::zero() {
-        T::zero_as_rocstr()
-    } else {
-        let mut value = value;
-        let mut len = 0;
-        let mut buffer = [b' '; SIZE];
-        while value > T::zero() {
-            len += 1;
-            let (next, char) = next_char(value);
-            buffer[SIZE - len] = char;
-            value = next;
+    /// Creates a [`RocStr`] from any integer, written in a given radix, with optional digit separators.
+    ///
+    /// Unlike strings, an integer is never trimmed, as a truncated number would be a different number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the integer is written with more than `SIZE` bytes, separators included,
+    /// use [`try_from_int_with`](Self::try_from_int_with) when the capacity may be too small.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::rocint::IntFormat;
+    /// # use rocstr::rocint::Radix;
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<8>::from_int_with(255_u8, Radix::UpperHex), "FF");
+    /// assert_eq!(RocStr::<8>::from_int_with(-255_i32, Radix::LowerHex), "-ff");
+    /// assert_eq!(RocStr::<8>::from_int_with(35_u8, Radix::Base36), "z");
+    ///
+    /// let bytes = IntFormat::new(Radix::Binary).with_separator('_', 4);
+    /// assert_eq!(RocStr::<16>::from_int_with(0xa5_u8, bytes), "1010_0101");
+    ///
+    /// let thousands = IntFormat::new(Radix::Decimal).with_separator(',', 3);
+    /// assert_eq!(RocStr::<16>::from_int_with(1_234_567_u32, thousands), "1,234,567");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_int_with<T: Integer, F: Into<IntFormat>>(value: T, format: F) -> Self {
+        match Self::try_from_int_with(value, format) {
+            Ok(s) => s,
+            Err(e) => panic!("{e}"),
         }
-        let mut inner = [b' '; SIZE];
-        inner[..len].copy_from_slice(&buffer[SIZE - len..]);
-
-        RocStr { inner, len }
     }
-}
 
-impl Zero for u8 {
-    fn zero() -> Self {
-        0
+    /// Creates a [`RocStr`] from any integer, written in a given radix, with optional digit separators,
+    /// failing if it does not fit in the capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the integer is written with more than `SIZE` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::rocint::Radix;
+    /// # use rocstr::RocStr;
+    /// assert_eq!(RocStr::<8>::try_from_int_with(u32::MAX, Radix::UpperHex).unwrap(), "FFFFFFFF");
+    /// assert!(RocStr::<8>::try_from_int_with(u32::MAX, Radix::Octal).is_err());
+    /// ```
+    #[inline]
+    pub fn try_from_int_with<T: Integer, F: Into<IntFormat>>(
+        value: T,
+        format: F,
+    ) -> core::result::Result<Self, InsufficientCapacity> {
+        Self::int_into(value, format.into()).try_finish_with(Operation::FromInt)
     }
-}
 
-impl Zero for u16 {
-    fn zero() -> Self {
-        0
-    }
-}
+    fn int_into<T: Integer>(value: T, format: IntFormat) -> RocStrBuilder<SIZE> {
+        let mut builder = RocStrBuilder::new();
+        write_int(&mut builder, value, format);
 
-impl Zero for u32 {
-    fn zero() -> Self {
-        0
+        builder
     }
-}
 
-impl Zero for u64 {
-    fn zero() -> Self {
-        0
+    /// Returns a copy of this [`RocStr`] with capacity set to `LEN`, failing if it does not fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if the length of this [`RocStr`] is greater than `LEN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<16>::from("foo");
+    /// assert_eq!(s.try_reshape::<3>().unwrap(), "foo");
+    /// assert!(s.try_reshape::<2>().is_err());
+    /// ```
+    #[inline]
+    pub fn try_reshape<const LEN: usize>(
+        &self,
+    ) -> core::result::Result<RocStr<LEN>, InsufficientCapacity> {
+        if self.len() > LEN {
+            Err(insufficient_capacity(
+                Operation::Reshape,
+                self.as_bytes(),
+                LEN,
+            ))
+        } else {
+            Ok(self.reshape())
+        }
     }
-}
 
-impl Zero for u128 {
-    fn zero() -> Self {
-        0
+    /// Returns a [`RocStr`] with exactly the first `len` bytes of this [`RocStr`].
+    ///
+    /// Unlike [`truncate`](Self::truncate), which silently steps back to the previous utf-8 boundary,
+    /// this fails if `len` is not a utf-8 boundary.
+    ///
+    /// If `len` is greater than or equal to the length of this [`RocStr`], it is returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InsufficientCapacity`] if `len` falls inside a multi-byte character.
+    ///
+    /// # Examples
+    /// ```
+    /// # use rocstr::RocStr;
+    /// let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+    ///
+    /// assert_eq!(s.try_truncate(6).unwrap(), "Löwe ");
+    ///
+    /// /* second byte of `老`is not utf-8 boundary */
+    /// assert!(s.try_truncate(8).is_err());
+    /// ```
+    #[inline]
+    pub fn try_truncate(&self, len: usize) -> core::result::Result<Self, InsufficientCapacity> {
+        let string = self.as_str();
+        if len > self.len() || string.is_char_boundary(len) {
+            Ok(self.truncate(len))
+        } else {
+            let mut required = len + 1;
+            while !string.is_char_boundary(required) {
+                required += 1;
+            }
+            let truncated_at = extract_utf8_within(self.as_bytes(), len).len();
+            Err(InsufficientCapacity::new(
+                Operation::Truncate,
+                required,
+                len,
+                truncated_at,
+            ))
+        }
     }
 }
 
-impl Zero for usize {
-    fn zero() -> Self {
-        0
-    }
+/// A target width for the padding methods of [`RocStr`], like [`RocStr::pad_left`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Width {
+    /// A width in chars, which is the display width of most latin, greek or cyrillic strings.
+    Chars(usize),
+    /// A width in bytes, which is the width of a field in a fixed width file.
+    ///
+    /// With a multi-byte fill char, the result may be shorter than this width, but never longer.
+    Bytes(usize),
 }
 
-impl Zero for i8 {
-    fn zero() -> Self {
-        0
+impl<const SIZE: usize> Debug for RocStr<SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let inner: &str = self.into();
+        if cfg!(feature = "debug-as-str") {
+            Debug::fmt(inner, f)
+        } else {
+            f.debug_struct("RocStr")
+                .field("inner", &inner)
+                .field("len", &self.len())
+                .finish()
+        }
     }
 }
 
-impl Zero for i16 {
-    fn zero() -> Self {
-        0
+impl<const SIZE: usize> Default for RocStr<SIZE> {
+    fn default() -> Self {
+        Self::from_parts([0; SIZE], 0)
     }
 }
 
-impl Zero for i32 {
-    fn zero() -> Self {
-        0
+impl<const SIZE: usize> Display for RocStr<SIZE> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        // Forward to `str` so that width, fill, alignment and precision are honored.
+        Display::fmt(self.as_str(), f)
     }
 }
 
-impl Zero for i64 {
-    fn zero() -> Self {
-        0
-    }
-}
+/// Parses a [`RocStr`] from a `&str`, failing if it does not fit in the capacity.
+///
+/// # Examples
+/// ```
+/// # use rocstr::RocStr;
+/// let s: RocStr<16> = "foo".parse().unwrap();
+/// assert_eq!(s, "foo");
+///
+/// assert!("foo".parse::<RocStr<2>>().is_err());
+/// ```
+impl<const SIZE: usize> FromStr for RocStr<SIZE> {
+    type Err = InsufficientCapacity;
 
-impl Zero for i128 {
-    fn zero() -> Self {
-        0
+    #[inline]
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        Self::try_from_str(s)
     }
 }
 
-impl Zero for isize {
-    fn zero() -> Self {
-        0
+/// Collects `&str` pieces into a [`RocStr`], silently trimmed to at most the capacity.
+///
+/// Collect into a [`RocStrBuilder`] and call [`try_finish`](RocStrBuilder::try_finish) to fail instead.
+impl<'a, const SIZE: usize> FromIterator<&'a str> for RocStr<SIZE> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        iter.into_iter().collect::<RocStrBuilder<SIZE>>().finish()
     }
 }
 
-impl Ten for u8 {
-    fn ten() -> Self {
-        10
+/// Collects chars into a [`RocStr`], silently trimmed to at most the capacity.
+///
+/// Collect into a [`RocStrBuilder`] and call [`try_finish`](RocStrBuilder::try_finish) to fail instead.
+impl<const SIZE: usize> FromIterator<char> for RocStr<SIZE> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        iter.into_iter().collect::<RocStrBuilder<SIZE>>().finish()
     }
 }
 
-impl Ten for u16 {
-    fn ten() -> Self {
-        10
-    }
-}
+// Ideally, the signature should be
+//     `fn from(value: T) -> Self where T: AsRef<str>`
+// But this conflict with other `From`` implementation.
+impl<const SIZE: usize> From<&str> for RocStr<SIZE> {
+    #[inline]
+    fn from(value: &str) -> Self {
+        let bytes = value.as_bytes();
+        let slice = extract_utf8_within(bytes, SIZE);
+        let len = slice.len();
 
-impl Ten for u32 {
-    fn ten() -> Self {
-        10
+        let mut inner = [0; SIZE];
+        inner[..len].copy_from_slice(slice);
+        Self::from_parts(inner, len)
     }
 }
 
-impl Ten for u64 {
-    fn ten() -> Self {
-        10
+impl<'a, const SIZE: usize> From<&'a RocStr<SIZE>> for &'a str {
+    #[inline]
+    fn from(value: &'a RocStr<SIZE>) -> Self {
+        match from_utf8(value.inner[..value.len()].as_ref()) {
+            Ok(string) => string,
+            // Unless unsafe use, this should never happen.
+            // This data is immutable and can only be initialized from a valid utf-8 string.
+            Err(_) => unreachable!(),
+        }
     }
 }
 
-impl Ten for u128 {
-    fn ten() -> Self {
-        10
+impl<'a, const SIZE: usize> From<&'a RocStr<SIZE>> for &'a [u8] {
+    #[inline]
+    fn from(value: &'a RocStr<SIZE>) -> Self {
+        &value.inner[..value.len()]
     }
 }
 
-impl Ten for usize {
-    fn ten() -> Self {
-        10
-    }
-}
+impl<const SIZE: usize, I> Index<I> for RocStr<SIZE>
+where
+    I: SliceIndex<str>,
+{
+    type Output = I::Output;
 
-impl Ten for i8 {
-    fn ten() -> Self {
-        10
+    #[inline]
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_str()[index]
     }
 }
 
-impl Ten for i16 {
-    fn ten() -> Self {
-        10
+impl<const SIZE: usize> Hash for RocStr<SIZE> {
+    #[inline]
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        hasher.write(&self.inner[..self.len()]);
+        hasher.write_u8(0xff);
     }
 }
 
-impl Ten for i32 {
-    fn ten() -> Self {
-        10
+impl<const SIZE: usize> PartialEq<str> for RocStr<SIZE> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.len() == other.len() && &self.inner[..self.len()] == other.as_bytes()
     }
 }
 
-impl Ten for i64 {
-    fn ten() -> Self {
-        10
+impl<const SIZE: usize, T> PartialEq<T> for RocStr<SIZE>
+where
+    T: AsRef<str>,
+{
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        let other = other.as_ref();
+        self.eq(other)
     }
 }
 
-impl Ten for i128 {
-    fn ten() -> Self {
-        10
+impl<const SIZE: usize> PartialEq<RocStr<SIZE>> for &str {
+    #[inline]
+    fn eq(&self, other: &RocStr<SIZE>) -> bool {
+        self.len() == other.len() && self.as_bytes() == &other.inner[..other.len()]
     }
 }
 
-impl Ten for isize {
-    fn ten() -> Self {
-        10
+impl<const SIZE: usize, const LEN: usize> PartialEq<RocStr<SIZE>> for RocStr<LEN> {
+    #[inline]
+    fn eq(&self, other: &RocStr<SIZE>) -> bool {
+        self.len() == other.len() && self.inner[..self.len()] == other.inner[..other.len()]
     }
 }
 
-impl AsDigit for u8 {
-    fn as_digit(&self) -> u8 {
-        *self
+// Ordering only compares the bytes within `len`, like `str`, consistently with `PartialEq`.
+impl<const SIZE: usize> Ord for RocStr<SIZE> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_bytes().cmp(other.as_bytes())
     }
 }
 
-impl AsDigit for u16 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
+impl<const SIZE: usize> PartialOrd<str> for RocStr<SIZE> {
+    #[inline]
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(self.as_bytes().cmp(other.as_bytes()))
     }
 }
 
-impl AsDigit for u32 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
+impl<const SIZE: usize, T> PartialOrd<T> for RocStr<SIZE>
+where
+    T: AsRef<str>,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+        let other = other.as_ref();
+        self.partial_cmp(other)
     }
 }
 
-impl AsDigit for u64 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
+impl<const SIZE: usize> PartialOrd<RocStr<SIZE>> for &str {
+    #[inline]
+    fn partial_cmp(&self, other: &RocStr<SIZE>) -> Option<Ordering> {
+        Some(self.as_bytes().cmp(other.as_bytes()))
     }
 }
 
-impl AsDigit for u128 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
+impl<const SIZE: usize, const LEN: usize> PartialOrd<RocStr<SIZE>> for RocStr<LEN> {
+    #[inline]
+    fn partial_cmp(&self, other: &RocStr<SIZE>) -> Option<Ordering> {
+        Some(self.as_bytes().cmp(other.as_bytes()))
     }
 }
 
-impl AsDigit for usize {
-    fn as_digit(&self) -> u8 {
-        *self as u8
-    }
-}
+impl<const SIZE: usize, T> Add<T> for RocStr<SIZE>
+where
+    T: AsRef<str>,
+{
+    type Output = Self;
 
-impl AsDigit for i8 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
+    fn add(self, rhs: T) -> Self::Output {
+        self.saturating_add(rhs.as_ref())
     }
 }
 
-impl AsDigit for i16 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
-    }
-}
+impl<const SIZE: usize, const LEN: usize> Add<RocStr<LEN>> for RocStr<SIZE> {
+    type Output = Self;
 
-impl AsDigit for i32 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
+    fn add(self, rhs: RocStr<LEN>) -> Self::Output {
+        self.saturating_add(rhs.as_str())
     }
 }
 
-impl AsDigit for i64 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
-    }
-}
+/// Extract a valid utf-8 string from this byte array with at most `len` bytes.
+///
+/// # Examples
+/// let s = "Löwe 老虎 Léopard";
+///
+/// /* first byte of `ö` is not utf-8 boundary */
+/// assert_eq!("L", extract_utf8_within(s.as_bytes(), 2));
+///
+/// /* second byte of `老`is not utf-8 boundary */
+/// assert_eq!("Löwe ", extract_utf8_within(s.as_bytes(), 8));
+#[inline]
+#[must_use]
+pub(crate) fn extract_utf8_within(bytes: &[u8], len: usize) -> &[u8] {
+    let bytes_len = bytes.len();
+    let boundary = if len < bytes_len {
+        // This is bit magic equivalent to: b < 128 || b >= 192
+        if (bytes[len] as i8) >= -0x40 {
+            len
+        } else {
+            let mut boundary = len - 1;
+            // This is bit magic equivalent to: b >= 128 && b <= 192
+            while (bytes[boundary] as i8) < -0x40 {
+                boundary -= 1;
+            }
+            boundary
+        }
+    } else {
+        bytes_len
+    };
 
-impl AsDigit for i128 {
-    fn as_digit(&self) -> u8 {
-        *self as u8
-    }
+    &bytes[..boundary]
 }
 
-impl AsDigit for isize {
-    fn as_digit(&self) -> u8 {
-        *self as u8
-    }
+/// Build the insufficient capacity error of an `operation` which cannot fit `bytes` in `capacity`.
+#[inline]
+#[must_use]
+fn insufficient_capacity(
+    operation: Operation,
+    bytes: &[u8],
+    capacity: usize,
+) -> InsufficientCapacity {
+    let truncated_at = extract_utf8_within(bytes, capacity).len();
+    InsufficientCapacity::new(operation, bytes.len(), capacity, truncated_at)
+}
+
+/// Whether the `Σ` at byte `index` of `s` ends a word, i.e. it follows a cased letter
+/// and is not followed by one, skipping case ignorable characters, as in `str::to_lowercase`.
+fn is_final_sigma(s: &str, index: usize) -> bool {
+    /// Unicode `Cased` : lowercase, uppercase and the few titlecase (`Lt`) letters.
+    fn is_cased(c: char) -> bool {
+        c.is_lowercase()
+            || c.is_uppercase()
+            || matches!(
+                c,
+                '\u{01C5}'
+                    | '\u{01C8}'
+                    | '\u{01CB}'
+                    | '\u{01F2}'
+                    | '\u{1F88}'..='\u{1F8F}'
+                    | '\u{1F98}'..='\u{1F9F}'
+                    | '\u{1FA8}'..='\u{1FAF}'
+                    | '\u{1FBC}'
+                    | '\u{1FCC}'
+                    | '\u{1FFC}'
+            )
+    }
+
+    /// A subset of Unicode `Case_Ignorable` : without the Unicode tables of `std`,
+    /// most nonspacing and enclosing marks (`Mn`, `Me`), format characters (`Cf`),
+    /// modifier letters (`Lm`) and modifier symbols (`Sk`) are not skipped.
+    fn is_case_ignorable(c: char) -> bool {
+        matches!(
+            c,
+            '\'' | '.' | ':' | '\u{00AD}' | '\u{00B7}' | '\u{2018}' | '\u{2019}' | '\u{0300}'
+                ..='\u{036F}'
+        )
+    }
+
+    let before = s[..index].chars().rev().find(|c| !is_case_ignorable(*c));
+    let after = s[index + 'Σ'.len_utf8()..]
+        .chars()
+        .find(|c| !is_case_ignorable(*c));
+
+    before.map_or(false, is_cased) && !after.map_or(false, is_cased)
 }
 
 impl From<u8> for RocStr<3> {
     fn from(value: u8) -> Self {
-        from_unsigned(value)
+        Self::from_int(value)
     }
 }
 
 impl From<u16> for RocStr<5> {
     fn from(value: u16) -> Self {
-        from_unsigned(value)
+        Self::from_int(value)
     }
 }
 
 impl From<u32> for RocStr<10> {
     fn from(value: u32) -> Self {
-        from_unsigned(value)
+        Self::from_int(value)
     }
 }
 
 impl From<u64> for RocStr<20> {
     fn from(value: u64) -> Self {
-        from_unsigned(value)
+        Self::from_int(value)
     }
 }
 
 impl From<u128> for RocStr<39> {
     fn from(value: u128) -> Self {
-        from_unsigned(value)
+        Self::from_int(value)
     }
 }
 
 impl From<usize> for RocStr<20> {
     fn from(value: usize) -> Self {
-        from_unsigned(value)
+        Self::from_int(value)
     }
 }
 
 impl From<i8> for RocStr<4> {
     fn from(value: i8) -> Self {
-        if value == i8::MIN {
-            ROCSTR_MIN_I8
-        } else {
-            from_signed(value)
-        }
+        Self::from_int(value)
     }
 }
 
 impl From<i16> for RocStr<6> {
     fn from(value: i16) -> Self {
-        if value == i16::MIN {
-            ROCSTR_MIN_I16
-        } else {
-            from_signed(value)
-        }
+        Self::from_int(value)
     }
 }
 
 impl From<i32> for RocStr<11> {
     fn from(value: i32) -> Self {
-        if value == i32::MIN {
-            ROCSTR_MIN_I32
-        } else {
-            from_signed(value)
-        }
+        Self::from_int(value)
     }
 }
 
 impl From<i64> for RocStr<20> {
     fn from(value: i64) -> Self {
-        if value == i64::MIN {
-            ROCSTR_MIN_I64
-        } else {
-            from_signed(value)
-        }
+        Self::from_int(value)
     }
 }
 
 impl From<i128> for RocStr<40> {
     fn from(value: i128) -> Self {
-        if value == i128::MIN {
-            RocStr::from("-170141183460469231731687303715884105728")
-        } else {
-            from_signed(value)
-        }
+        Self::from_int(value)
     }
 }
 
 impl From<isize> for RocStr<20> {
     fn from(value: isize) -> Self {
-        if value == isize::MIN {
-            ROCSTR_MIN_ISIZE
-        } else {
-            from_signed(value)
-        }
+        Self::from_int(value)
     }
 }
 
+/// Compile time check that a string literal of `LEN` bytes fits in a [`RocStr<SIZE>`].
+struct LiteralFits<const LEN: usize, const SIZE: usize>;
+
+impl<const LEN: usize, const SIZE: usize> LiteralFits<LEN, SIZE> {
+    const OK: () = assert!(
+        LEN <= SIZE,
+        "the string literal does not fit in the RocStr capacity"
+    );
+}
+
+/// Creates a [`RocStr`] from a string literal.
+///
+/// The capacity is either explicit, `rocstr!(SIZE; "...")`, or inferred, `rocstr!("...")`.
+/// In both cases, the literal is checked at compile time
+/// and an oversized literal fails the compilation, wherever the macro is used.
+///
+/// # Examples
+/// ```
+/// # use rocstr::rocstr;
+/// # use rocstr::RocStr;
+/// static COUNTRIES: [RocStr<2>; 3] = [rocstr!("FR"), rocstr!("DE"), rocstr!("IT")];
+///
+/// let status = rocstr!(8; "pending");
+///
+/// assert_eq!(COUNTRIES[0], "FR");
+/// assert_eq!(status.capacity(), 8);
+/// ```
+///
+/// ```compile_fail
+/// # use rocstr::rocstr;
+/// let status = rocstr!(4; "pending");
+/// ```
+///
+/// ```compile_fail
+/// # use rocstr::rocstr;
+/// # use rocstr::RocStr;
+/// let x: RocStr<2> = rocstr!("FRA");
+/// ```
+#[macro_export]
+macro_rules! rocstr {
+    ($size:expr; $value:expr) => {{
+        const ROCSTR: $crate::RocStr<$size> = $crate::RocStr::from_str_const($value);
+        ROCSTR
+    }};
+    ($value:expr) => {
+        $crate::RocStr::from_literal::<{ $value.len() }>($value)
+    };
+}
+
 #[cfg(feature = "std")]
 mod standard_rocstr {
     extern crate std;
 
+    use core::cmp::Ordering;
+
     use super::RocStr;
 
     use std::string::String;
 
     impl<const SIZE: usize> From<String> for RocStr<SIZE> {
         #[inline]
-        #[must_use]
         fn from(value: String) -> Self {
             Self::from(value.as_str())
         }
@@ -905,7 +2342,6 @@ mod standard_rocstr {
 
     impl<const SIZE: usize> From<&String> for RocStr<SIZE> {
         #[inline]
-        #[must_use]
         fn from(value: &String) -> Self {
             Self::from(value.as_str())
         }
@@ -916,6 +2352,12 @@ mod standard_rocstr {
             self.eq(other.as_str())
         }
     }
+
+    impl<const SIZE: usize> PartialOrd<RocStr<SIZE>> for String {
+        fn partial_cmp(&self, other: &RocStr<SIZE>) -> Option<Ordering> {
+            self.as_str().partial_cmp(other.as_str())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -935,57 +2377,550 @@ mod tests {
     }
 
     #[test]
-    fn rocstr_should_equal_inner_str() {
-        let s = RocStr::<16>::from("foo");
-        assert_eq!(s, "foo");
+    fn rocstr_should_equal_inner_str() {
+        let s = RocStr::<16>::from("foo");
+        assert_eq!(s, "foo");
+    }
+
+    #[test]
+    fn rocstr_ref_should_equal_inner_str() {
+        let s = RocStr::<16>::from("foo");
+        assert_eq!(&s, "foo");
+    }
+
+    #[test]
+    fn rocstr_capacity_should_be_its_generic_parameter_size() {
+        let string = RocStr::<16>::from("");
+        assert_eq!(string.capacity(), 16);
+    }
+
+    #[test]
+    fn empty_rocstr_should_say_it_is_empty() {
+        let s = RocStr::<16>::from("");
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn not_empty_rocstr_should_say_it_is_not_empty() {
+        let s = RocStr::<16>::from("foo");
+        assert!(!s.is_empty());
+    }
+
+    #[test]
+    fn rocstr_len_should_count_the_number_of_bytes() {
+        let s = RocStr::<16>::from("foo");
+        assert_eq!(s.len(), 3);
+    }
+
+    #[test]
+    fn reshaped_rocstr_should_have_the_new_capacity() {
+        let s = RocStr::<16>::from("foo");
+        assert_eq!(s.reshape::<8>().capacity(), 8);
+    }
+
+    #[test]
+    fn rocstr_starts_with_should_return_true_if_it_starts_with() {
+        let bananas = RocStr::<16>::from("bananas");
+        assert!(bananas.starts_with("bana"));
+    }
+
+    #[test]
+    fn rocstr_starts_with_should_return_false_if_it_does_not_start_with() {
+        let bananas = RocStr::<16>::from("bananas");
+        assert!(!bananas.starts_with("nana"));
+    }
+
+    #[test]
+    fn rocstr_ends_with_should_return_true_if_it_ends_with() {
+        let bananas = RocStr::<16>::from("bananas");
+        assert!(bananas.ends_with("nas"));
+        assert!(bananas.ends_with(""));
+    }
+
+    #[test]
+    fn rocstr_ends_with_should_return_false_if_it_does_not_end_with() {
+        let bananas = RocStr::<16>::from("bananas");
+        assert!(!bananas.ends_with("nan"));
+    }
+
+    #[test]
+    fn rocstr_contains_should_return_whether_it_contains_the_str() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+
+        assert!(s.contains("老虎"));
+        assert!(!s.contains("Tiger"));
+    }
+
+    #[test]
+    fn rocstr_find_and_rfind_should_return_the_byte_index_of_the_match() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard 老虎");
+
+        assert_eq!(s.find("老虎"), Some(6));
+        assert_eq!(s.rfind("老虎"), Some(22));
+        assert_eq!(s.find("Tiger"), None);
+        assert_eq!(s.rfind("Tiger"), None);
+    }
+
+    #[test]
+    fn rocstr_matches_should_iterate_over_the_matches() {
+        let s = RocStr::<16>::from("abcXXXabcYYYabc");
+        let mut matches = s.matches("abc");
+
+        assert_eq!(matches.next(), Some("abc"));
+        assert_eq!(matches.count(), 2);
+    }
+
+    #[test]
+    fn rocstr_strip_prefix_and_suffix_should_be_owned_rocstrs() {
+        let s = RocStr::<16>::from("<Löwe>");
+        let stripped = s.strip_prefix("<").and_then(|s| s.strip_suffix(">"));
+
+        assert_eq!(stripped, Some(RocStr::<16>::from("Löwe")));
+        assert_eq!(s.strip_prefix(">"), None);
+        assert_eq!(s.strip_suffix("<"), None);
+    }
+
+    #[test]
+    fn rocstr_split_once_should_be_the_owned_parts_around_the_first_delimiter() {
+        let s = RocStr::<16>::from("key=value=42");
+        assert_eq!(
+            s.split_once("="),
+            Some((RocStr::from("key"), RocStr::from("value=42")))
+        );
+        assert_eq!(s.split_once(":"), None);
+    }
+
+    #[test]
+    fn rocstr_rsplit_once_should_be_the_owned_parts_around_the_last_delimiter() {
+        let s = RocStr::<16>::from("key=value=42");
+        assert_eq!(
+            s.rsplit_once("="),
+            Some((RocStr::from("key=value"), RocStr::from("42")))
+        );
+        assert_eq!(s.rsplit_once(":"), None);
+    }
+
+    #[test]
+    fn rocstr_get_should_be_the_owned_range() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+
+        assert_eq!(s.get(..), Some(s));
+        assert_eq!(s.get(6..=11), Some(RocStr::from("老虎")));
+        assert_eq!(s.get(13..), Some(RocStr::from("Léopard")));
+    }
+
+    #[test]
+    fn rocstr_get_should_be_none_out_of_bounds_or_utf8_boundaries() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+
+        assert_eq!(s.get(2..), None);
+        assert_eq!(s.get(..22), None);
+        let (start, end) = (12, 6);
+        assert_eq!(s.get(start..end), None);
+        assert_eq!(s.get(..=usize::MAX), None);
+        assert_eq!(s.get((Bound::Excluded(usize::MAX), Bound::Unbounded)), None);
+        assert_eq!(
+            s.get((Bound::Excluded(12), Bound::Included(20))),
+            Some(RocStr::from("Léopard"))
+        );
+    }
+
+    #[test]
+    fn rocstr_slice_should_step_back_to_utf8_boundaries() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+
+        assert_eq!(s.slice(2..3), "ö");
+        assert_eq!(s.slice(2..=2), "ö");
+        assert_eq!(s.slice(7..=10), "老");
+        let (start, end) = (12, 6);
+        assert_eq!(s.slice(start..end), "");
+        assert_eq!(s.slice(..), s);
+    }
+
+    #[test]
+    fn rocstr_slice_should_keep_zero_padding() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+        let sliced = s.slice(13..);
+
+        assert_eq!(sliced, RocStr::<32>::from("Léopard"));
+        assert_eq!(sliced.as_bytes().len(), 8);
+    }
+
+    #[test]
+    fn rocstr_index_should_be_the_str_range() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+
+        assert_eq!(&s[..], "Löwe 老虎 Léopard");
+        assert_eq!(&s[6..12], "老虎");
+        assert_eq!(&s[13..], "Léopard");
+        assert_eq!(&s[..=5], "Löwe ");
+    }
+
+    #[test]
+    #[should_panic]
+    fn rocstr_index_should_panic_inside_a_char() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+        let _ = &s[..7];
+    }
+
+    #[test]
+    fn rocstr_take_and_skip_chars_should_count_chars() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+
+        assert_eq!(s.take_chars(0), "");
+        assert_eq!(s.take_chars(2), "Lö");
+        assert_eq!(s.skip_chars(2), "we 老虎 Léopard");
+        assert_eq!(s.skip_chars(0), s);
+        assert_eq!(s.take_chars(15), s);
+        assert_eq!(s.skip_chars(15), "");
+    }
+
+    #[test]
+    fn rocstr_trim_should_remove_unicode_whitespace() {
+        let s = RocStr::<32>::from("\u{3000}\t Löwe 老虎\u{a0}\n");
+
+        assert_eq!(s.trim(), "Löwe 老虎");
+        assert_eq!(s.trim_start(), "Löwe 老虎\u{a0}\n");
+        assert_eq!(s.trim_end(), "\u{3000}\t Löwe 老虎");
+    }
+
+    #[test]
+    fn rocstr_trim_should_keep_zero_padding() {
+        let s = RocStr::<16>::from("   Löwe   ");
+
+        assert_eq!(s.trim(), RocStr::<16>::from("Löwe"));
+        assert_eq!(s.trim_ascii(), RocStr::<16>::from("Löwe"));
+    }
+
+    #[test]
+    fn rocstr_trim_blank_padded_char_should_be_the_value() {
+        let s = RocStr::<8>::from("FR      ");
+        assert_eq!(s.trim_end(), "FR");
+        assert_eq!(s.trim_ascii_end(), "FR");
+    }
+
+    #[test]
+    fn rocstr_trim_matches_should_remove_repeated_pattern() {
+        let s = RocStr::<16>::from("abab老abab");
+
+        assert_eq!(s.trim_matches("ab"), "老");
+        assert_eq!(s.trim_start_matches("ab"), "老abab");
+        assert_eq!(s.trim_end_matches("ab"), "abab老");
+        assert_eq!(s.trim_matches(""), s);
+    }
+
+    #[test]
+    fn rocstr_trim_by_should_remove_chars_matching_the_predicate() {
+        let s = RocStr::<16>::from("老Löwe虎");
+        let not_ascii = |c: char| !c.is_ascii();
+
+        assert_eq!(s.trim_by(not_ascii), "Löwe");
+        assert_eq!(s.trim_start_by(not_ascii), "Löwe虎");
+        assert_eq!(s.trim_end_by(not_ascii), "老Löwe");
+    }
+
+    #[test]
+    fn rocstr_trim_ascii_should_not_remove_unicode_whitespace() {
+        let s = RocStr::<16>::from(" \u{3000}Löwe\u{3000} ");
+
+        assert_eq!(s.trim_ascii(), "\u{3000}Löwe\u{3000}");
+        assert_eq!(s.trim_ascii_start(), "\u{3000}Löwe\u{3000} ");
+        assert_eq!(s.trim_ascii_end(), " \u{3000}Löwe\u{3000}");
+    }
+
+    #[test]
+    fn rocstr_trim_whitespace_only_should_be_empty() {
+        let s = RocStr::<8>::from(" \t\n ");
+
+        assert!(s.trim().is_empty());
+        assert!(s.trim_ascii().is_empty());
+        assert!(s.trim_ascii_start().is_empty());
+        assert!(s.trim_ascii_end().is_empty());
+    }
+
+    #[test]
+    fn rocstr_to_ascii_case_should_only_map_ascii_letters() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard ÉÏ");
+
+        assert_eq!(s.to_ascii_uppercase(), "LöWE 老虎 LéOPARD ÉÏ");
+        assert_eq!(s.to_ascii_lowercase(), "löwe 老虎 léopard ÉÏ");
+    }
+
+    #[test]
+    fn rocstr_to_ascii_case_should_keep_zero_padding() {
+        let s = RocStr::<16>::from("Löwe");
+
+        assert_eq!(s.to_ascii_uppercase(), RocStr::<16>::from("LöWE"));
+        assert_eq!(s.to_ascii_lowercase(), RocStr::<16>::from("löwe"));
+    }
+
+    #[test]
+    fn rocstr_to_uppercase_should_be_like_str_to_uppercase() {
+        extern crate std;
+
+        let s = RocStr::<32>::from("Löwe 老虎 Straße ﬁx");
+        assert_eq!(
+            s.to_uppercase(),
+            std::string::String::from(s.as_str()).to_uppercase()
+        );
+        assert_eq!(s.try_to_uppercase().unwrap(), "LÖWE 老虎 STRASSE FIX");
+    }
+
+    #[test]
+    fn rocstr_to_lowercase_should_be_like_str_to_lowercase() {
+        extern crate std;
+
+        let s = RocStr::<32>::from("LÖWE 老虎 İSTANBUL");
+        assert_eq!(
+            s.to_lowercase(),
+            std::string::String::from(s.as_str()).to_lowercase()
+        );
+        assert_eq!(s.try_to_lowercase().unwrap(), "löwe 老虎 i̇stanbul");
+    }
+
+    #[test]
+    fn rocstr_to_lowercase_final_sigma_should_be_like_str_to_lowercase() {
+        extern crate std;
+
+        for value in [
+            "ΟΔΟΣ",
+            "ΣΑΣ ΣΑΣ",
+            "Σ",
+            "ΑΣΑ",
+            "ΑΣ.",
+            "ΑΣ'Α",
+            "ΆΣ\u{301}",
+            "ΑΣ1",
+            "1Σ",
+            "ΑΣ-ΑΣ",
+            "ǅΣ",
+            "ᾈΣ ǲΣ",
+        ] {
+            let s = RocStr::<16>::from(value);
+            assert_eq!(
+                s.to_lowercase(),
+                std::string::String::from(value).to_lowercase(),
+                "❌ {value}"
+            );
+        }
+        assert_eq!(RocStr::<16>::from("ΟΔΟΣ").to_lowercase(), "οδος");
+    }
+
+    #[test]
+    fn rocstr_to_uppercase_without_enough_capacity_should_be_truncated() {
+        let s = RocStr::<8>::from("Löwe ŉ");
+
+        assert_eq!(s.to_uppercase(), "LÖWE ʼ");
+        assert_eq!(
+            s.try_to_uppercase(),
+            Err(InsufficientCapacity::new(Operation::ToUppercase, 9, 8, 8))
+        );
+        assert_eq!(
+            RocStr::<8>::from("ßßßß").try_to_uppercase().unwrap(),
+            "SSSSSSSS"
+        );
+    }
+
+    #[test]
+    fn rocstr_to_lowercase_without_enough_capacity_should_fail() {
+        let s = RocStr::<4>::from("İİ");
+
+        assert_eq!(s.to_lowercase(), "i̇i");
+        assert_eq!(
+            s.try_to_lowercase(),
+            Err(InsufficientCapacity::new(Operation::ToLowercase, 6, 4, 4))
+        );
+    }
+
+    #[test]
+    fn rocstr_split_should_be_like_str_split() {
+        let s = RocStr::<32>::from("Löwe,老虎,,Léopard");
+        let expected = s.as_str().split(",").map(RocStr::<32>::from);
+
+        assert!(s.split(",").eq(expected));
+        assert!(s
+            .rsplit(",")
+            .eq(s.as_str().rsplit(",").map(RocStr::<32>::from)));
+        assert_eq!(s.split(",").nth(2), Some(RocStr::default()));
+    }
+
+    #[test]
+    fn rocstr_splitn_should_stop_after_n_pieces() {
+        let s = RocStr::<32>::from("Löwe,老虎,,Léopard");
+        let mut pieces = s.splitn(2, ",");
+
+        assert_eq!(pieces.next(), Some(RocStr::from("Löwe")));
+        assert_eq!(pieces.next(), Some(RocStr::from("老虎,,Léopard")));
+        assert_eq!(pieces.next(), None);
+    }
+
+    #[test]
+    fn rocstr_split_whitespace_lines_and_terminator_should_be_like_str() {
+        let s = RocStr::<32>::from("Löwe 老虎\n\nLéopard\n");
+
+        assert!(s
+            .split_whitespace()
+            .eq(s.as_str().split_whitespace().map(RocStr::<32>::from)));
+        assert!(s.lines().eq(s.as_str().lines().map(RocStr::<32>::from)));
+        assert!(s
+            .split_terminator("\n")
+            .eq(s.as_str().split_terminator("\n").map(RocStr::<32>::from)));
+        assert_eq!(s.lines().count(), 3);
+    }
+
+    #[test]
+    fn rocstr_split_array_should_be_the_exact_pieces() {
+        let s = RocStr::<16>::from("a|b||c");
+
+        assert_eq!(
+            s.split_array("|"),
+            Ok([
+                RocStr::from("a"),
+                RocStr::from("b"),
+                RocStr::default(),
+                RocStr::from("c")
+            ])
+        );
+        assert_eq!(s.split_array::<1>(","), Ok([s]));
+    }
+
+    #[test]
+    fn rocstr_split_array_with_wrong_piece_count_should_fail() {
+        let s = RocStr::<16>::from("a|b||c");
+
+        assert_eq!(s.split_array::<3>("|"), Err(PieceCountError::new(3, 4)));
+        assert_eq!(s.split_array::<5>("|"), Err(PieceCountError::new(5, 4)));
+        assert_eq!(s.split_array::<0>("|"), Err(PieceCountError::new(0, 4)));
+    }
+
+    #[test]
+    fn rocstr_join_should_put_the_separator_between_pieces() {
+        assert_eq!(
+            RocStr::<32>::join(", ", ["Löwe", "老虎", "Léopard"]),
+            "Löwe, 老虎, Léopard"
+        );
+        assert_eq!(RocStr::<32>::join(", ", ["Löwe"]), "Löwe");
+        assert_eq!(RocStr::<32>::join(", ", [""; 0]), "");
+        assert_eq!(RocStr::<32>::join("", ["Löwe", "老虎"]), "Löwe老虎");
+    }
+
+    #[test]
+    fn rocstr_join_should_accept_owned_and_borrowed_pieces() {
+        extern crate std;
+        use std::string::String;
+
+        let pieces = [String::from("Löwe"), String::from("老虎")];
+        assert_eq!(RocStr::<32>::join("-", &pieces), "Löwe-老虎");
+        assert_eq!(RocStr::<32>::join("-", pieces), "Löwe-老虎");
+    }
+
+    #[test]
+    fn rocstr_join_without_enough_capacity_should_be_truncated() {
+        let pieces = ["Löwe", "老虎", "Léopard"];
+
+        assert_eq!(RocStr::<8>::join(", ", pieces), "Löwe, ");
+        assert_eq!(
+            RocStr::<8>::overflowing_join(", ", pieces),
+            (RocStr::from("Löwe, "), 16)
+        );
+        assert_eq!(
+            RocStr::<8>::try_join(", ", pieces),
+            Err(InsufficientCapacity::new(Operation::Join, 23, 8, 7))
+        );
     }
 
     #[test]
-    fn rocstr_ref_should_equal_inner_str() {
-        let s = RocStr::<16>::from("foo");
-        assert_eq!(&s, "foo");
+    fn rocstr_collect_should_concatenate_the_pieces() {
+        let s: RocStr<16> = ["Löwe", " ", "老虎"].into_iter().collect();
+        assert_eq!(s, "Löwe 老虎");
+
+        let s: RocStr<16> = "Löwe 老虎".chars().rev().collect();
+        assert_eq!(s, "虎老 ewöL");
     }
 
     #[test]
-    fn rocstr_capacity_should_be_its_generic_parameter_size() {
-        let string = RocStr::<16>::from("");
-        assert_eq!(string.capacity(), 16);
+    fn rocstr_collect_without_enough_capacity_should_be_truncated() {
+        let s: RocStr<8> = ["Löwe", " ", "老虎"].into_iter().collect();
+        assert_eq!(s, "Löwe ");
+
+        let s: RocStr<8> = "Löwe 老虎".chars().collect();
+        assert_eq!(s, "Löwe ");
     }
 
     #[test]
-    fn empty_rocstr_should_say_it_is_empty() {
-        let s = RocStr::<16>::from("");
-        assert!(s.is_empty());
+    fn rocstr_pad_should_be_like_format_alignment() {
+        extern crate std;
+        use std::format;
+
+        let s = RocStr::<32>::from("Löwe 老虎");
+
+        assert_eq!(
+            s.pad_left(Width::Chars(10), '_'),
+            format!("{:_>10}", s.as_str())
+        );
+        assert_eq!(
+            s.pad_right(Width::Chars(10), '_'),
+            format!("{:_<10}", s.as_str())
+        );
+        assert_eq!(
+            s.center(Width::Chars(10), '_'),
+            format!("{:_^10}", s.as_str())
+        );
+        assert_eq!(
+            s.center(Width::Chars(11), '_'),
+            format!("{:_^11}", s.as_str())
+        );
     }
 
     #[test]
-    fn not_empty_rocstr_should_say_it_is_not_empty() {
-        let s = RocStr::<16>::from("foo");
-        assert!(!s.is_empty());
+    fn rocstr_pad_in_bytes_should_never_exceed_the_width() {
+        let s = RocStr::<32>::from("Löwe");
+
+        assert_eq!(s.pad_left(Width::Bytes(8), ' '), "   Löwe");
+        assert_eq!(s.pad_right(Width::Bytes(9), '老'), "Löwe老");
+        assert_eq!(s.center(Width::Bytes(12), '老'), "老Löwe老");
     }
 
     #[test]
-    fn rocstr_len_should_count_the_number_of_bytes() {
-        let s = RocStr::<16>::from("foo");
-        assert_eq!(s.len(), 3);
+    fn rocstr_pad_narrower_width_should_be_unchanged() {
+        let s = RocStr::<16>::from("Löwe");
+
+        assert_eq!(s.pad_left(Width::Chars(2), '_'), s);
+        assert_eq!(s.pad_right(Width::Bytes(5), '_'), s);
+        assert_eq!(s.center(Width::Chars(0), '_'), s);
     }
 
     #[test]
-    fn reshaped_rocstr_should_have_the_new_capacity() {
-        let s = RocStr::<16>::from("foo");
-        assert_eq!(s.reshape::<8>().capacity(), 8);
+    fn rocstr_pad_wider_than_capacity_should_be_truncated_or_fail() {
+        let s = RocStr::<8>::from("Löwe");
+
+        assert_eq!(s.pad_left(Width::Chars(10), '_'), "______L");
+        assert_eq!(s.pad_right(Width::Chars(10), '_'), "Löwe___");
+        assert_eq!(
+            s.try_pad_left(Width::Chars(10), '_'),
+            Err(InsufficientCapacity::new(Operation::Pad, 11, 8, 7))
+        );
+        assert_eq!(
+            s.try_center(Width::Chars(10), '_'),
+            Err(InsufficientCapacity::new(Operation::Pad, 11, 8, 8))
+        );
     }
 
     #[test]
-    fn rocstr_starts_with_should_return_true_if_it_starts_with() {
-        let bananas = RocStr::<16>::from("bananas");
-        assert!(bananas.starts_with("bana"));
+    fn rocstr_zfill_should_keep_the_sign_in_front() {
+        assert_eq!(RocStr::<8>::from("-42").zfill(6), "-00042");
+        assert_eq!(RocStr::<8>::from("+42").zfill(6), "+00042");
+        assert_eq!(RocStr::<8>::from("42").zfill(6), "000042");
+        assert_eq!(RocStr::<8>::from("-").zfill(3), "-00");
+        assert_eq!(RocStr::<8>::from("").zfill(3), "000");
+        assert_eq!(RocStr::<8>::from("-42").zfill(2), "-42");
     }
 
     #[test]
-    fn rocstr_starts_with_should_return_false_if_it_does_not_start_with() {
-        let bananas = RocStr::<16>::from("bananas");
-        assert!(!bananas.starts_with("nana"));
+    fn rocstr_zfill_of_integer_conversion_should_be_zero_padded() {
+        let s: RocStr<8> = RocStr::from(i8::MIN).reshape();
+        assert_eq!(s.zfill(8), "-0000128");
+        assert_eq!(s.try_zfill(9).unwrap_err().required, 9);
     }
 
     #[test]
@@ -1348,6 +3283,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "debug-as-str"))]
     fn rocerr_debug_info_should_display_inner_field_as_str() {
         extern crate std;
         use std::format;
@@ -1360,6 +3296,32 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(feature = "debug-as-str")]
+    fn rocerr_debug_info_should_display_as_a_str_literal() {
+        extern crate std;
+        use std::format;
+
+        let s = RocStr::<16>::from("foo \"bar\"");
+        assert_eq!(format!("{s:?}"), format!("{:?}", "foo \"bar\""));
+    }
+
+    #[test]
+    fn rocstr_display_should_honor_the_formatter_flags() {
+        extern crate std;
+        use std::format;
+
+        let s = RocStr::<16>::from("Löwe");
+
+        assert_eq!(format!("{s}"), "Löwe");
+        assert_eq!(format!("{s:>8}"), "    Löwe");
+        assert_eq!(format!("{s:<8}|"), "Löwe    |");
+        assert_eq!(format!("{s:*^8}"), "**Löwe**");
+        assert_eq!(format!("{s:.2}"), "Lö");
+        assert_eq!(format!("{s:-<6.2}"), "Lö----");
+        assert_eq!(format!("{s:>w$.p$}", w = 4, p = 3), " Löw");
+    }
+
     #[test]
     fn hash_rocstr_should_be_the_hash_of_the_inner_str() {
         extern crate std;
@@ -1377,6 +3339,149 @@ mod tests {
         assert_eq!(hash, exptected);
     }
 
+    #[test]
+    fn rocstr_should_only_add_an_u32_length_to_its_capacity() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<RocStr<8>>(), 12);
+        assert_eq!(size_of::<RocStr<16>>(), 20);
+        assert_eq!(size_of::<RocStr<64>>(), 68);
+        assert_eq!(size_of::<RocStr<3>>(), 8);
+    }
+
+    #[test]
+    fn optional_rocstr_should_have_the_same_size_as_rocstr() {
+        use core::mem::size_of;
+
+        assert_eq!(size_of::<Option<RocStr<0>>>(), size_of::<RocStr<0>>());
+        assert_eq!(size_of::<Option<RocStr<1>>>(), size_of::<RocStr<1>>());
+        assert_eq!(size_of::<Option<RocStr<8>>>(), size_of::<RocStr<8>>());
+        assert_eq!(size_of::<Option<RocStr<16>>>(), size_of::<RocStr<16>>());
+        assert_eq!(size_of::<Option<RocStr<64>>>(), size_of::<RocStr<64>>());
+        assert_eq!(size_of::<Option<RocStr<255>>>(), size_of::<RocStr<255>>());
+    }
+
+    #[test]
+    fn rocstr_from_str_const_should_be_the_str() {
+        const FOO: RocStr<16> = RocStr::from_str_const("Löwe 老虎");
+        assert_eq!(FOO, "Löwe 老虎");
+        assert_eq!(FOO, RocStr::<16>::from("Löwe 老虎"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rocstr_from_str_const_without_enough_capacity_should_panic_at_runtime() {
+        let value = "foo";
+        let _ = RocStr::<2>::from_str_const(value);
+    }
+
+    #[test]
+    fn rocstr_macro_should_build_static_tables() {
+        static STATUSES: [RocStr<8>; 3] = [rocstr!("pending"), rocstr!("running"), rocstr!("done")];
+
+        assert_eq!(STATUSES[0], "pending");
+        assert_eq!(STATUSES[2], "done");
+    }
+
+    #[test]
+    fn rocstr_macro_with_inferred_capacity_should_build_local_values() {
+        let s: RocStr<8> = rocstr!("FR");
+
+        assert_eq!(s, "FR");
+        assert_eq!(s.capacity(), 8);
+    }
+
+    #[test]
+    fn rocstr_macro_with_capacity_should_have_the_capacity() {
+        let s = rocstr!(16; "foo");
+
+        assert_eq!(s, "foo");
+        assert_eq!(s.capacity(), 16);
+    }
+
+    #[test]
+    fn str_parsed_to_rocstr_should_be_the_str() {
+        let s = "Löwe 老虎".parse::<RocStr<16>>();
+        assert_eq!(s, Ok(RocStr::<16>::from("Löwe 老虎")));
+    }
+
+    #[test]
+    fn str_parsed_to_rocstr_without_enough_capacity_should_fail() {
+        let s = "Löwe 老虎".parse::<RocStr<8>>();
+        assert_eq!(
+            s,
+            Err(InsufficientCapacity::new(Operation::FromStr, 12, 8, 6))
+        );
+    }
+
+    #[test]
+    fn rocstr_parsed_to_a_number_should_be_the_number() {
+        assert_eq!(RocStr::<8>::from("-42").parse::<i32>(), Ok(-42));
+        assert_eq!(RocStr::<8>::from("3.5").parse::<f64>(), Ok(3.5));
+        assert!(RocStr::<8>::from("4x2").parse::<i32>().is_err());
+    }
+
+    #[test]
+    fn rocstr_ordering_should_ignore_the_bytes_after_len() {
+        let s = RocStr::<8>::from("ab");
+        let t = RocStr::<8>::from("abcd").truncate(2);
+
+        assert_eq!(s.cmp(&t), Ordering::Equal);
+        assert_eq!(s.partial_cmp(&t), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn rocstr_ordering_should_be_the_lexicographic_ordering_of_str() {
+        let samples = ["", "a", "ab", "abc", "b", "ba", "Löwe", "老虎"];
+        for a in samples {
+            for b in samples {
+                let s = RocStr::<16>::from(a);
+                let t = RocStr::<16>::from(b);
+                assert_eq!(s.cmp(&t), a.cmp(b), "❌ {a} cmp {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn rocstrs_with_different_capacities_should_be_ordered_as_str() {
+        let s = RocStr::<4>::from("abc");
+        let t = RocStr::<16>::from("abd");
+
+        assert!(s < t);
+        assert!(t > s);
+        assert_eq!(
+            s.partial_cmp(&RocStr::<16>::from("abc")),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn rocstr_should_be_ordered_with_str() {
+        let s = RocStr::<16>::from("bananas");
+
+        assert!(s > "apple");
+        assert!(s < "cherry");
+        assert!("apple" < s);
+        assert_eq!(s.partial_cmp("bananas"), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn rocstr_btreemap_should_be_sorted_as_str() {
+        extern crate std;
+        use std::collections::BTreeMap;
+        use std::vec::Vec;
+
+        let mut map = BTreeMap::new();
+        map.insert(RocStr::<8>::from("pear"), 1);
+        map.insert(RocStr::<8>::from("apple pie").truncate(5), 2);
+        map.insert(RocStr::<8>::from("fig"), 3);
+        map.insert(RocStr::<8>::from("apple"), 4);
+
+        let keys = map.keys().map(RocStr::as_str).collect::<Vec<_>>();
+        assert_eq!(keys, ["apple", "fig", "pear"]);
+        assert_eq!(map[&RocStr::from("apple")], 4);
+    }
+
     #[test]
     fn replace_an_str_at_the_begining_of_a_rocstr_should_be_the_rocstr_with_str_replaced() {
         let s = RocStr::<16>::from("this is old");
@@ -1401,7 +3506,7 @@ mod tests {
         let replaced = s.replace("old", "obvously overflowing");
 
         assert!(
-            replaced.len <= replaced.capacity(),
+            replaced.len() <= replaced.capacity(),
             "Len of replaced rocstr is greater than its capacity"
         );
 
@@ -1414,7 +3519,7 @@ mod tests {
         let replaced = s.replace("is", "is obvously");
 
         assert!(
-            replaced.len <= replaced.capacity(),
+            replaced.len() <= replaced.capacity(),
             "Len of replaced rocstr is greater than its capacity"
         );
 
@@ -1427,7 +3532,7 @@ mod tests {
         let replaced = s.replace(" is", " is obvously");
 
         assert!(
-            replaced.len <= replaced.capacity(),
+            replaced.len() <= replaced.capacity(),
             "Len of replaced rocstr is greater than its capacity"
         );
 
@@ -1442,6 +3547,254 @@ mod tests {
         assert_eq!(s.truncate(8), "Löwe ");
     }
 
+    #[test]
+    fn try_from_str_with_enough_capacity_should_be_the_str() {
+        let s = RocStr::<16>::try_from_str("foo");
+        assert_eq!(s, Ok(RocStr::<16>::from("foo")));
+    }
+
+    #[test]
+    fn try_from_str_with_exactly_the_capacity_should_be_the_str() {
+        let s = RocStr::<3>::try_from_str("foo");
+        assert_eq!(s, Ok(RocStr::<3>::from("foo")));
+    }
+
+    #[test]
+    fn try_from_str_without_enough_capacity_should_fail() {
+        let s = RocStr::<16>::try_from_str("Löwe 老虎 Léopard");
+        assert_eq!(
+            s,
+            Err(InsufficientCapacity::new(Operation::FromStr, 21, 16, 16))
+        );
+    }
+
+    #[test]
+    fn try_from_bytes_with_a_valid_utf8_should_be_the_str() {
+        let s = RocStr::<16>::try_from_bytes("Löwe".as_bytes());
+        assert_eq!(s, Ok(RocStr::<16>::from("Löwe")));
+    }
+
+    #[test]
+    fn try_from_bytes_with_an_invalid_utf8_should_fail() {
+        let s = RocStr::<16>::try_from_bytes(&[b'L', 0xc3]);
+        assert!(matches!(s, Err(FromBytesError::Utf8(_))));
+    }
+
+    #[test]
+    fn try_from_bytes_without_enough_capacity_should_fail() {
+        let s = RocStr::<2>::try_from_bytes(b"foo");
+        assert_eq!(
+            s,
+            Err(FromBytesError::InsufficientCapacity(
+                InsufficientCapacity::new(Operation::FromBytes, 3, 2, 2)
+            ))
+        );
+    }
+
+    #[test]
+    fn try_reshape_with_enough_capacity_should_be_the_same_str() {
+        let s = RocStr::<16>::from("foo");
+        assert_eq!(s.try_reshape::<3>(), Ok(RocStr::<3>::from("foo")));
+    }
+
+    #[test]
+    fn try_reshape_without_enough_capacity_should_fail() {
+        let s = RocStr::<16>::from("foo bar");
+        assert_eq!(
+            s.try_reshape::<4>(),
+            Err(InsufficientCapacity::new(Operation::Reshape, 7, 4, 4))
+        );
+    }
+
+    #[test]
+    fn try_truncate_on_a_utf8_boundary_should_be_the_truncated_str() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+        assert_eq!(s.try_truncate(6), Ok(RocStr::<32>::from("Löwe ")));
+        assert_eq!(s.try_truncate(64), Ok(s));
+    }
+
+    #[test]
+    fn try_truncate_inside_a_char_should_fail() {
+        let s = RocStr::<32>::from("Löwe 老虎 Léopard");
+
+        /* second byte of `老`is not utf-8 boundary */
+        assert_eq!(
+            s.try_truncate(8),
+            Err(InsufficientCapacity::new(Operation::Truncate, 9, 8, 6))
+        );
+    }
+
+    #[test]
+    fn try_add_with_enough_capacity_should_be_the_concatenation() {
+        let s = RocStr::<16>::from("Löwe 老虎 ");
+        assert_eq!(s.try_add("Lé"), Ok(RocStr::<16>::from("Löwe 老虎 Lé")));
+    }
+
+    #[test]
+    fn try_add_without_enough_capacity_should_fail() {
+        let s = RocStr::<16>::from("Löwe 老虎 ");
+        assert_eq!(
+            s.try_add("Léopard"),
+            Err(InsufficientCapacity::new(Operation::Add, 21, 16, 16))
+        );
+    }
+
+    #[test]
+    fn overflowing_add_should_report_the_dropped_bytes() {
+        let s = RocStr::<16>::from("Löwe 老虎 ");
+        let (added, dropped) = s.overflowing_add("Léopard");
+
+        assert_eq!(added, "Löwe 老虎 Lé");
+        assert_eq!(dropped, 5);
+    }
+
+    #[test]
+    fn overflowing_add_should_report_the_bytes_dropped_at_utf8_boundary() {
+        let s = RocStr::<16>::from("Löwe 老虎 L");
+        let (added, dropped) = s.overflowing_add("éé");
+
+        assert_eq!(added, "Löwe 老虎 Lé");
+        assert_eq!(dropped, 2);
+    }
+
+    #[test]
+    fn concat_without_enough_capacity_should_drop_the_pieces_after_the_overflow() {
+        let s = RocStr::<8>::concat(&["foo", " bar baz", "!"]);
+        assert_eq!(s, "foo bar ");
+    }
+
+    #[test]
+    fn try_concat_with_enough_capacity_should_be_the_concatenation() {
+        let s = RocStr::<16>::try_concat(&["foo", " ", "bar"]);
+        assert_eq!(s, Ok(RocStr::<16>::from("foo bar")));
+    }
+
+    #[test]
+    fn try_concat_without_enough_capacity_should_fail() {
+        let s = RocStr::<6>::try_concat(&["foo", " ", "bar"]);
+        assert_eq!(
+            s,
+            Err(InsufficientCapacity::new(Operation::Concat, 7, 6, 6))
+        );
+    }
+
+    #[test]
+    fn overflowing_concat_should_report_the_dropped_bytes() {
+        let (s, dropped) = RocStr::<8>::overflowing_concat(&["foo", " bar baz", "!"]);
+
+        assert_eq!(s, "foo bar ");
+        assert_eq!(dropped, 4);
+    }
+
+    #[test]
+    fn try_replace_with_enough_capacity_should_be_the_rocstr_with_str_replaced() {
+        let s = RocStr::<16>::from("this is old");
+        assert_eq!(
+            s.try_replace("is", "an"),
+            Ok(RocStr::<16>::from("than an old"))
+        );
+    }
+
+    #[test]
+    fn try_replace_without_enough_capacity_should_fail() {
+        let s = RocStr::<16>::from("this is old");
+        assert_eq!(
+            s.try_replace("is", "is obvously"),
+            Err(InsufficientCapacity::new(Operation::Replace, 29, 16, 16))
+        );
+    }
+
+    #[test]
+    fn overflowing_replace_should_report_the_dropped_bytes() {
+        let s = RocStr::<16>::from("this is old");
+        let (replaced, dropped) = s.overflowing_replace("is", "is obvously");
+
+        assert_eq!(replaced, "this obvously is");
+        assert_eq!(dropped, 13);
+    }
+
+    #[test]
+    fn replace_a_multibyte_str_that_overflow_should_be_a_valid_utf8() {
+        let s = RocStr::<8>::from("foo bar");
+        assert_eq!(s.replace("bar", "老虎"), "foo 老");
+    }
+
+    mod zero_padding {
+        use proptest::prelude::*;
+
+        use super::*;
+
+        fn is_zero_padded<const SIZE: usize>(s: &RocStr<SIZE>) -> bool {
+            s.inner[s.len()..].iter().all(|b| *b == 0)
+        }
+
+        #[test]
+        fn default_rocstr_should_be_zero_padded() {
+            assert!(is_zero_padded(&RocStr::<16>::default()));
+        }
+
+        #[test]
+        fn zero_converted_to_rocstr_should_be_zero_padded() {
+            assert!(is_zero_padded(&RocStr::from(0u64)));
+            assert!(is_zero_padded(&RocStr::from(0i64)));
+        }
+
+        proptest! {
+            #[test]
+            fn rocstr_from_str_should_be_zero_padded(value in "\\PC{0,24}") {
+                prop_assert!(is_zero_padded(&RocStr::<16>::from(value.as_str())));
+            }
+
+            #[test]
+            fn reshaped_rocstr_should_be_zero_padded(value in "\\PC{0,16}") {
+                let s = RocStr::<16>::from(value.as_str());
+                prop_assert!(is_zero_padded(&s.reshape::<8>()));
+                prop_assert!(is_zero_padded(&s.reshape::<32>()));
+            }
+
+            #[test]
+            fn truncated_rocstr_should_be_zero_padded(value in "\\PC{0,16}", len in 0..20usize) {
+                let s = RocStr::<16>::from(value.as_str());
+                prop_assert!(is_zero_padded(&s.truncate(len)));
+            }
+
+            #[test]
+            fn replaced_rocstr_should_be_zero_padded(
+                value in "[ab老]{0,16}",
+                from in "[ab老]{1,2}",
+                to in "\\PC{0,4}",
+            ) {
+                let s = RocStr::<16>::from(value.as_str());
+                prop_assert!(is_zero_padded(&s.replace(&from, &to)));
+            }
+
+            #[test]
+            fn concatenated_rocstr_should_be_zero_padded(a in "\\PC{0,16}", b in "\\PC{0,16}") {
+                let s = RocStr::<16>::from(a.as_str());
+                let t = RocStr::<16>::from(b.as_str());
+                prop_assert!(is_zero_padded(&(s + t)));
+                prop_assert!(is_zero_padded(&(s + b.as_str())));
+                prop_assert!(is_zero_padded(&RocStr::<16>::concat(&[&a, &b])));
+            }
+
+            #[test]
+            fn converted_integers_should_be_zero_padded(u in any::<u64>(), i in any::<i64>()) {
+                prop_assert!(is_zero_padded(&RocStr::from(u)));
+                prop_assert!(is_zero_padded(&RocStr::from(i)));
+                prop_assert!(is_zero_padded(&RocStr::from(u as u8)));
+                prop_assert!(is_zero_padded(&RocStr::from(i as i8)));
+            }
+
+            #[test]
+            fn equal_rocstrs_should_have_equal_backing_arrays(value in "\\PC{0,16}", len in 0..20usize) {
+                let s = RocStr::<16>::from(value.as_str()).truncate(len);
+                let t = RocStr::<16>::from(s.as_str());
+                prop_assert_eq!(s, t);
+                prop_assert_eq!(s.inner, t.inner);
+            }
+        }
+    }
+
     #[cfg(feature = "std")]
     mod standard_rocstr {
         extern crate std;
@@ -1456,5 +3809,13 @@ mod tests {
 
             assert_eq!(s, RocStr::<32>::from(&s));
         }
+
+        #[test]
+        fn string_should_be_ordered_with_rocstr() {
+            let s = String::from("apple");
+
+            assert!(s < RocStr::<16>::from("bananas"));
+            assert!(s > RocStr::<16>::from("Apple"));
+        }
     }
 }
@@ -0,0 +1,1024 @@
+//! Serde Serialize and Deserialize implementation
+//!
+//! By default, a string longer than the capacity is silently trimmed when deserialized.
+//! With the `serde-strict` feature, it is rejected with an `invalid_length` error instead.
+//!
+//! Either behavior can be chosen per field with the [`strict`] and [`lossy`] modules.
+//!
+//! In self-describing formats, like JSON, booleans, numbers and `null` are converted to strings by default.
+//! This can be turned off with a [`Policy`], and [`deserialize_with_policy`].
+//!
+//! For fixed width records in binary formats, the [`fixed`] module encodes the whole backing array instead of a string.
+//!
+//! # Examples
+//! ```
+//! # use rocstr::RocStr;
+//! # use serde::Deserialize;
+//! #[derive(Deserialize)]
+//! struct Customer {
+//!     #[serde(with = "rocstr::serde::strict")]
+//!     name: RocStr<8>,
+//!     #[serde(with = "rocstr::serde::lossy")]
+//!     comment: RocStr<8>,
+//! }
+//!
+//! let customer: Customer = serde_json::from_str(r#"{"name":"Alice","comment":"Lorem ipsum"}"#).unwrap();
+//! assert_eq!(customer.comment, "Lorem ip");
+//!
+//! let customer = serde_json::from_str::<Customer>(r#"{"name":"Alice Adams","comment":""}"#);
+//! assert!(customer.is_err());
+//! ```
+
+use core::fmt;
+use core::fmt::Write;
+
+use serde::de::Unexpected;
+use serde::de::Visitor;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::rocbuilder::RocStrBuilder;
+use crate::rocstr::RocStr;
+
+/// How a [`RocStr`] is deserialized, see [`deserialize_with_policy`].
+///
+/// # Examples
+/// ```
+/// # use rocstr::serde::deserialize_with_policy;
+/// # use rocstr::serde::Policy;
+/// # use rocstr::RocStr;
+/// # use serde::Deserialize;
+/// # use serde::Deserializer;
+/// fn string_only<'de, D, const SIZE: usize>(deserializer: D) -> Result<RocStr<SIZE>, D::Error>
+/// where
+///     D: Deserializer<'de>,
+/// {
+///     deserialize_with_policy(deserializer, Policy::new().strict(true).coerce_scalars(false))
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Product {
+///     #[serde(deserialize_with = "string_only")]
+///     sku: RocStr<8>,
+/// }
+///
+/// assert!(serde_json::from_str::<Product>(r#"{"sku":"FR-75"}"#).is_ok());
+/// assert!(serde_json::from_str::<Product>(r#"{"sku":75}"#).is_err());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Policy {
+    /// Rejects strings longer than the capacity with an `invalid_length` error, instead of trimming them.
+    pub strict: bool,
+    /// Converts booleans, numbers and unit values to strings, instead of rejecting them with an `invalid_type` error.
+    pub coerce_scalars: bool,
+}
+
+impl Policy {
+    /// The policy of the `Deserialize` implementation of [`RocStr`] :
+    /// strict only with the `serde-strict` feature, and coercing scalars.
+    pub const DEFAULT: Self = Self {
+        strict: cfg!(feature = "serde-strict"),
+        coerce_scalars: true,
+    };
+
+    /// Creates the [`DEFAULT`](Self::DEFAULT) policy.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    /// Returns this policy, rejecting strings longer than the capacity or not.
+    #[inline]
+    #[must_use]
+    pub const fn strict(self, strict: bool) -> Self {
+        Self { strict, ..self }
+    }
+
+    /// Returns this policy, converting booleans, numbers and unit values to strings or not.
+    #[inline]
+    #[must_use]
+    pub const fn coerce_scalars(self, coerce_scalars: bool) -> Self {
+        Self {
+            coerce_scalars,
+            ..self
+        }
+    }
+}
+
+impl Default for Policy {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl<const SIZE: usize> Serialize for RocStr<SIZE> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de, const SIZE: usize> Deserialize<'de> for RocStr<SIZE> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_with_policy(deserializer, Policy::DEFAULT)
+    }
+}
+
+/// Deserializes a [`RocStr`] following a [`Policy`].
+///
+/// # Errors
+///
+/// Returns an `invalid_length` error if the string is longer than `SIZE` bytes and the policy is strict,
+/// an `invalid_type` error if the value is not a string and the policy does not coerce scalars,
+/// or the deserializer error.
+pub fn deserialize_with_policy<'de, D, const SIZE: usize>(
+    deserializer: D,
+    policy: Policy,
+) -> Result<RocStr<SIZE>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let visitor = RocStrVisitor::<SIZE> { policy };
+    // Self-describing human readable formats may hold scalars which are converted to strings,
+    // but non-self-describing formats, like bincode or postcard, need to be told what to read
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_str(visitor)
+    }
+}
+
+/// Strict (de)serialization, to be used with `#[serde(with = "rocstr::serde::strict")]`.
+///
+/// A string longer than the capacity is rejected with an `invalid_length` error.
+pub mod strict {
+    use super::*;
+
+    /// Serializes a [`RocStr`] as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer error.
+    #[inline]
+    pub fn serialize<S, const SIZE: usize>(
+        value: &RocStr<SIZE>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a [`RocStr`], failing if the string does not fit in the capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `invalid_length` error if the string is longer than `SIZE` bytes,
+    /// or the deserializer error.
+    #[inline]
+    pub fn deserialize<'de, D, const SIZE: usize>(deserializer: D) -> Result<RocStr<SIZE>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_with_policy(deserializer, Policy::DEFAULT.strict(true))
+    }
+}
+
+/// Lossy (de)serialization, to be used with `#[serde(with = "rocstr::serde::lossy")]`.
+///
+/// A string longer than the capacity is silently trimmed to a valid utf-8 string.
+pub mod lossy {
+    use super::*;
+
+    /// Serializes a [`RocStr`] as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer error.
+    #[inline]
+    pub fn serialize<S, const SIZE: usize>(
+        value: &RocStr<SIZE>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a [`RocStr`], silently trimmed to at most the capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserializer error.
+    #[inline]
+    pub fn deserialize<'de, D, const SIZE: usize>(deserializer: D) -> Result<RocStr<SIZE>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_with_policy(deserializer, Policy::DEFAULT.strict(false))
+    }
+}
+
+/// Fixed width (de)serialization, to be used with `#[serde(with = "rocstr::serde::fixed")]`.
+///
+/// A [`RocStr<SIZE>`] is serialized as a tuple of `4 + SIZE` bytes :
+/// the length of the string as a little endian `u32`, followed by the whole backing array,
+/// zero padded after the string.
+/// Every value of a given capacity has the same encoded size in binary formats like bincode or postcard,
+/// which makes records fixed width.
+///
+/// # Examples
+/// ```
+/// # use rocstr::RocStr;
+/// # use serde::Deserialize;
+/// # use serde::Serialize;
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "rocstr::serde::fixed")]
+///     code: RocStr<8>,
+/// }
+///
+/// let short = bincode::serialize(&Record { code: RocStr::from("FR") }).unwrap();
+/// let long = bincode::serialize(&Record { code: RocStr::from("FR-75-01") }).unwrap();
+/// assert_eq!(short.len(), 4 + 8);
+/// assert_eq!(long.len(), 4 + 8);
+///
+/// let record: Record = bincode::deserialize(&short).unwrap();
+/// assert_eq!(record.code, "FR");
+/// ```
+pub mod fixed {
+    use serde::de::SeqAccess;
+    use serde::ser::SerializeTuple;
+
+    use super::*;
+
+    /// Size of the length prefix, in bytes.
+    const PREFIX: usize = core::mem::size_of::<u32>();
+
+    /// Serializes a [`RocStr`] as its length and its zero padded backing array.
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer error.
+    pub fn serialize<S, const SIZE: usize>(
+        value: &RocStr<SIZE>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `len <= SIZE < u32::MAX`, so the length always fits in the prefix
+        let len = value.len() as u32;
+        let mut tuple = serializer.serialize_tuple(PREFIX + SIZE)?;
+        for byte in len.to_le_bytes() {
+            tuple.serialize_element(&byte)?;
+        }
+        for byte in value.as_bytes() {
+            tuple.serialize_element(byte)?;
+        }
+        for _ in value.len()..SIZE {
+            tuple.serialize_element(&0_u8)?;
+        }
+        tuple.end()
+    }
+
+    /// Deserializes a [`RocStr`] from its length and its zero padded backing array.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `invalid_length` error if there are fewer than `4 + SIZE` bytes,
+    /// an `invalid_value` error if the length is greater than `SIZE`,
+    /// if the string is not valid utf-8 or if the padding is not zeroed,
+    /// or the deserializer error.
+    pub fn deserialize<'de, D, const SIZE: usize>(deserializer: D) -> Result<RocStr<SIZE>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(PREFIX + SIZE, FixedVisitor::<SIZE>)
+    }
+
+    struct FixedVisitor<const SIZE: usize>;
+
+    impl<const SIZE: usize> FixedVisitor<SIZE> {
+        /// Reads the next byte of the tuple, `index` being its position, for error reporting.
+        fn next_byte<'de, A>(&self, seq: &mut A, index: usize) -> Result<u8, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            match seq.next_element()? {
+                Some(byte) => Ok(byte),
+                None => Err(serde::de::Error::invalid_length(index, self)),
+            }
+        }
+    }
+
+    impl<'de, const SIZE: usize> Visitor<'de> for FixedVisitor<SIZE> {
+        type Value = RocStr<SIZE>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a little endian u32 length followed by {SIZE} bytes of zero padded utf-8"
+            )
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut prefix = [0; PREFIX];
+            for (index, byte) in prefix.iter_mut().enumerate() {
+                *byte = self.next_byte(&mut seq, index)?;
+            }
+            let mut inner = [0; SIZE];
+            for (index, byte) in inner.iter_mut().enumerate() {
+                *byte = self.next_byte(&mut seq, PREFIX + index)?;
+            }
+
+            let len = u32::from_le_bytes(prefix);
+            let len = match usize::try_from(len) {
+                Ok(len) if len <= SIZE => len,
+                _ => {
+                    return Err(serde::de::Error::invalid_value(
+                        Unexpected::Unsigned(u64::from(len)),
+                        &self,
+                    ))
+                }
+            };
+            let (string, padding) = inner.split_at(len);
+            if core::str::from_utf8(string).is_err() {
+                return Err(serde::de::Error::invalid_value(
+                    Unexpected::Bytes(string),
+                    &self,
+                ));
+            }
+            if padding.iter().any(|byte| *byte != 0) {
+                return Err(serde::de::Error::invalid_value(
+                    Unexpected::Bytes(padding),
+                    &self,
+                ));
+            }
+
+            Ok(RocStr::from_parts(inner, len))
+        }
+    }
+}
+
+struct RocStrVisitor<const SIZE: usize> {
+    policy: Policy,
+}
+
+impl<const SIZE: usize> RocStrVisitor<SIZE> {
+    /// Builds the [`RocStr`], failing in strict mode if `value` does not fit in the capacity.
+    fn build<E>(&self, value: &str) -> Result<RocStr<SIZE>, E>
+    where
+        E: serde::de::Error,
+    {
+        if self.policy.strict && value.len() > SIZE {
+            Err(E::invalid_length(value.len(), self))
+        } else {
+            Ok(RocStr::from(value))
+        }
+    }
+
+    /// Builds the [`RocStr`] of a formatted scalar, failing if the policy does not coerce scalars,
+    /// or in strict mode if it does not fit in the capacity.
+    ///
+    /// Scalars are formatted without allocation, so it works without `std`.
+    fn coerce<E>(
+        &self,
+        value: fmt::Arguments<'_>,
+        unexpected: Unexpected<'_>,
+    ) -> Result<RocStr<SIZE>, E>
+    where
+        E: serde::de::Error,
+    {
+        if !self.policy.coerce_scalars {
+            return Err(E::invalid_type(unexpected, self));
+        }
+
+        let mut builder = RocStrBuilder::<SIZE>::new();
+        builder
+            .write_fmt(value)
+            .expect("a formatting trait implementation returned an error");
+        if self.policy.strict && builder.is_truncated() {
+            Err(E::invalid_length(builder.required(), self))
+        } else {
+            Ok(builder.finish())
+        }
+    }
+}
+
+impl<'de, const SIZE: usize> Visitor<'de> for RocStrVisitor<SIZE> {
+    type Value = RocStr<SIZE>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a valid utf-8 string of at most {SIZE} bytes")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.coerce(format_args!("{v}"), Unexpected::Bool(v))
+    }
+
+    fn visit_i8<E>(self, v: i8) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_i64(i64::from(v))
+    }
+
+    fn visit_i16<E>(self, v: i16) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_i64(i64::from(v))
+    }
+
+    fn visit_i32<E>(self, v: i32) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_i64(i64::from(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.coerce(format_args!("{v}"), Unexpected::Signed(v))
+    }
+
+    fn visit_i128<E>(self, v: i128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.coerce(format_args!("{v}"), Unexpected::Other("i128"))
+    }
+
+    fn visit_u8<E>(self, v: u8) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_u64(u64::from(v))
+    }
+
+    fn visit_u16<E>(self, v: u16) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_u64(u64::from(v))
+    }
+
+    fn visit_u32<E>(self, v: u32) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_u64(u64::from(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.coerce(format_args!("{v}"), Unexpected::Unsigned(v))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.coerce(format_args!("{v}"), Unexpected::Other("u128"))
+    }
+
+    fn visit_f32<E>(self, v: f32) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        // Formatted as f32, `0.1_f32` is `0.1`, but `0.10000000149011612` as f64
+        self.coerce(format_args!("{v}"), Unexpected::Float(f64::from(v)))
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.coerce(format_args!("{v}"), Unexpected::Float(v))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.coerce(format_args!(""), Unexpected::Unit)
+    }
+
+    fn visit_char<E>(self, v: char) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let mut buffer = [0; 4];
+        self.visit_str(v.encode_utf8(&mut buffer))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.build(v)
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.build(v)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(v) => self.build(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_bytes(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    struct SerdeStruct {
+        id: u64,
+        name: RocStr<64>,
+    }
+
+    #[test]
+    fn serialized_to_json_should_be_the_values_as_json() {
+        let value = SerdeStruct {
+            id: 42,
+            name: "foo".into(),
+        };
+
+        let serialized = serde_json::to_string(&value);
+        assert!(serialized.is_ok(), "❌ {}", serialized.err().unwrap());
+
+        let serialized = serialized.unwrap();
+        assert_eq!(serialized, r#"{"id":42,"name":"foo"}"#);
+    }
+
+    #[test]
+    fn deserialized_from_json_should_contain_the_values() {
+        let expected = SerdeStruct {
+            id: 42,
+            name: "foo".into(),
+        };
+
+        let deserialized = serde_json::from_str::<SerdeStruct>(r#"{"id":42,"name":"foo"}"#);
+
+        assert!(deserialized.is_ok(), "❌ {}", deserialized.err().unwrap());
+
+        let deserialized = deserialized.unwrap();
+        assert_eq!(deserialized, expected);
+    }
+
+    #[test]
+    fn serialized_to_toml_should_be_the_values_as_toml() {
+        let value = SerdeStruct {
+            id: 42,
+            name: "foo".into(),
+        };
+
+        let serialized = toml::to_string(&value);
+        assert!(serialized.is_ok(), "❌ {}", serialized.err().unwrap());
+
+        let serialized = serialized.unwrap();
+        assert_eq!(serialized, "id = 42\nname = \"foo\"\n");
+    }
+
+    #[test]
+    fn deserialized_from_toml_should_contain_the_values() {
+        let expected = SerdeStruct {
+            id: 42,
+            name: "foo".into(),
+        };
+
+        let deserialized = toml::from_str::<SerdeStruct>("id = 42\nname = \"foo\"\n");
+
+        match deserialized {
+            Ok(deserialized) => assert_eq!(deserialized, expected),
+            Err(e) => panic!("❌ {e}"),
+        }
+    }
+
+    #[test]
+    fn deserialized_from_bincode_should_be_the_serialized_values() {
+        let value = SerdeStruct {
+            id: 42,
+            name: "Löwe 老虎".into(),
+        };
+
+        let serialized = bincode::serialize(&value).unwrap();
+        let deserialized = bincode::deserialize::<SerdeStruct>(&serialized);
+
+        match deserialized {
+            Ok(deserialized) => assert_eq!(deserialized, value),
+            Err(e) => panic!("❌ {e}"),
+        }
+    }
+
+    #[test]
+    fn deserialized_from_postcard_should_be_the_serialized_values() {
+        let value = SerdeStruct {
+            id: 42,
+            name: "Löwe 老虎".into(),
+        };
+
+        let serialized = postcard::to_allocvec(&value).unwrap();
+        let deserialized = postcard::from_bytes::<SerdeStruct>(&serialized);
+
+        match deserialized {
+            Ok(deserialized) => assert_eq!(deserialized, value),
+            Err(e) => panic!("❌ {e}"),
+        }
+    }
+
+    #[test]
+    fn deserialized_from_compact_messagepack_should_be_the_serialized_values() {
+        let value = SerdeStruct {
+            id: 42,
+            name: "Löwe 老虎".into(),
+        };
+
+        let serialized = rmp_serde::to_vec(&value).unwrap();
+        let deserialized = rmp_serde::from_slice::<SerdeStruct>(&serialized);
+
+        match deserialized {
+            Ok(deserialized) => assert_eq!(deserialized, value),
+            Err(e) => panic!("❌ {e}"),
+        }
+    }
+
+    #[test]
+    fn deserialized_from_utf8_bytes_should_be_the_string() {
+        use serde::de::value::BorrowedBytesDeserializer;
+        use serde::de::value::BytesDeserializer;
+        use serde::de::value::Error;
+
+        let bytes = "Löwe 老虎".as_bytes();
+
+        let deserialized = RocStr::<16>::deserialize(BytesDeserializer::<Error>::new(bytes));
+        assert_eq!(deserialized.unwrap(), "Löwe 老虎");
+
+        let deserialized =
+            RocStr::<16>::deserialize(BorrowedBytesDeserializer::<Error>::new(bytes));
+        assert_eq!(deserialized.unwrap(), "Löwe 老虎");
+    }
+
+    #[test]
+    fn deserialized_from_invalid_utf8_bytes_should_fail() {
+        use serde::de::value::BytesDeserializer;
+        use serde::de::value::Error;
+
+        let deserialized =
+            RocStr::<16>::deserialize(BytesDeserializer::<Error>::new(&[0xff, 0xfe]));
+        assert!(deserialized.is_err());
+    }
+
+    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    struct StrictLossyStruct {
+        #[serde(with = "strict")]
+        strict: RocStr<8>,
+        #[serde(with = "lossy")]
+        lossy: RocStr<8>,
+    }
+
+    #[test]
+    fn deserialized_strict_longer_than_capacity_should_fail_with_the_capacity() {
+        extern crate std;
+        use std::string::ToString;
+
+        let deserialized =
+            serde_json::from_str::<StrictLossyStruct>(r#"{"strict":"Löwe 老虎","lossy":""}"#);
+
+        match deserialized {
+            Ok(deserialized) => panic!("❌ {deserialized:?}"),
+            Err(e) => assert!(
+                e.to_string().starts_with(
+                    "invalid length 12, expected a valid utf-8 string of at most 8 bytes"
+                ),
+                "❌ {e}"
+            ),
+        }
+    }
+
+    #[test]
+    fn deserialized_strict_longer_than_capacity_from_binary_formats_should_fail() {
+        let value = ("Löwe 老虎", "");
+
+        let serialized = bincode::serialize(&value).unwrap();
+        assert!(bincode::deserialize::<StrictLossyStruct>(&serialized).is_err());
+
+        let serialized = postcard::to_allocvec(&value).unwrap();
+        assert!(postcard::from_bytes::<StrictLossyStruct>(&serialized).is_err());
+    }
+
+    #[test]
+    fn deserialized_strict_and_lossy_within_capacity_should_be_the_values() {
+        let deserialized =
+            serde_json::from_str::<StrictLossyStruct>(r#"{"strict":"Lö 老","lossy":"Lö 老"}"#);
+
+        match deserialized {
+            Ok(deserialized) => {
+                assert_eq!(deserialized.strict, "Lö 老");
+                assert_eq!(deserialized.lossy, "Lö 老");
+            }
+            Err(e) => panic!("❌ {e}"),
+        }
+    }
+
+    #[test]
+    fn deserialized_lossy_longer_than_capacity_should_be_truncated() {
+        let deserialized =
+            serde_json::from_str::<StrictLossyStruct>(r#"{"strict":"","lossy":"Löwe 老虎"}"#);
+
+        match deserialized {
+            Ok(deserialized) => assert_eq!(deserialized.lossy, "Löwe "),
+            Err(e) => panic!("❌ {e}"),
+        }
+    }
+
+    #[test]
+    fn deserialized_strict_number_longer_than_capacity_should_fail() {
+        #[derive(Debug, Deserialize)]
+        struct Number {
+            #[serde(with = "strict")]
+            #[allow(dead_code)]
+            value: RocStr<4>,
+        }
+
+        assert!(serde_json::from_str::<Number>(r#"{"value":1234}"#).is_ok());
+        assert!(serde_json::from_str::<Number>(r#"{"value":-1234}"#).is_err());
+    }
+
+    #[test]
+    fn deserialized_by_default_longer_than_capacity_should_follow_the_serde_strict_feature() {
+        let deserialized = serde_json::from_str::<RocStr<8>>(r#""Löwe 老虎""#);
+
+        if cfg!(feature = "serde-strict") {
+            assert!(deserialized.is_err());
+        } else {
+            assert_eq!(deserialized.unwrap(), "Löwe ");
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq, Deserialize)]
+    struct Scalars {
+        boolean: RocStr<8>,
+        integer: RocStr<8>,
+        float: RocStr<8>,
+        unit: RocStr<8>,
+    }
+
+    #[test]
+    fn deserialized_scalars_should_be_coerced_to_strings() {
+        let deserialized = serde_json::from_str::<Scalars>(
+            r#"{"boolean":true,"integer":-42,"float":3.14,"unit":null}"#,
+        );
+
+        match deserialized {
+            Ok(deserialized) => {
+                assert_eq!(deserialized.boolean, "true");
+                assert_eq!(deserialized.integer, "-42");
+                assert_eq!(deserialized.float, "3.14");
+                assert_eq!(deserialized.unit, "");
+            }
+            Err(e) => panic!("❌ {e}"),
+        }
+    }
+
+    #[test]
+    fn deserialized_floats_should_be_formatted_in_their_own_precision() {
+        use serde::de::value::Error;
+        use serde::de::value::F32Deserializer;
+        use serde::de::value::F64Deserializer;
+
+        let deserialized = RocStr::<16>::deserialize(F32Deserializer::<Error>::new(0.1));
+        assert_eq!(deserialized.unwrap(), "0.1");
+
+        let deserialized = RocStr::<16>::deserialize(F64Deserializer::<Error>::new(-2.5e-3));
+        assert_eq!(deserialized.unwrap(), "-0.0025");
+    }
+
+    #[test]
+    fn deserialized_small_and_wide_integers_should_be_coerced_to_strings() {
+        use serde::de::value::Error;
+        use serde::de::value::I128Deserializer;
+        use serde::de::value::I8Deserializer;
+        use serde::de::value::U128Deserializer;
+        use serde::de::value::U16Deserializer;
+
+        let deserialized = RocStr::<4>::deserialize(I8Deserializer::<Error>::new(i8::MIN));
+        assert_eq!(deserialized.unwrap(), "-128");
+
+        let deserialized = RocStr::<8>::deserialize(U16Deserializer::<Error>::new(u16::MAX));
+        assert_eq!(deserialized.unwrap(), "65535");
+
+        let deserialized = RocStr::<40>::deserialize(I128Deserializer::<Error>::new(i128::MIN));
+        assert_eq!(
+            deserialized.unwrap(),
+            "-170141183460469231731687303715884105728"
+        );
+
+        let deserialized = RocStr::<40>::deserialize(U128Deserializer::<Error>::new(u128::MAX));
+        assert_eq!(
+            deserialized.unwrap(),
+            "340282366920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    fn deserialized_scalars_without_coercion_should_fail() {
+        fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<RocStr<8>, D::Error> {
+            deserialize_with_policy(deserializer, Policy::new().coerce_scalars(false))
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct StringOnly {
+            #[serde(deserialize_with = "deserialize")]
+            value: RocStr<8>,
+        }
+
+        extern crate std;
+        use std::format;
+
+        for scalar in ["true", "42", "-42", "3.14", "null"] {
+            let json = format!(r#"{{"value":{scalar}}}"#);
+            let deserialized = serde_json::from_str::<StringOnly>(&json);
+            assert!(deserialized.is_err(), "❌ {scalar} was coerced");
+        }
+
+        let deserialized = serde_json::from_str::<StringOnly>(r#"{"value":"42"}"#);
+        assert_eq!(deserialized.unwrap().value, "42");
+    }
+
+    #[test]
+    fn deserialized_strict_float_longer_than_capacity_should_fail() {
+        use serde::de::value::Error;
+        use serde::de::value::F64Deserializer;
+        use serde::de::IntoDeserializer;
+
+        let deserialized = deserialize_with_policy::<_, 8>(
+            F64Deserializer::<Error>::new(1e300),
+            Policy::new().strict(true),
+        );
+        assert!(deserialized.is_err());
+
+        let deserialized: Result<RocStr<8>, Error> =
+            deserialize_with_policy(1e300.into_deserializer(), Policy::new().strict(false));
+        assert_eq!(deserialized.unwrap(), "10000000");
+    }
+
+    #[test]
+    fn default_policy_should_follow_the_serde_strict_feature() {
+        assert_eq!(Policy::default().strict, cfg!(feature = "serde-strict"));
+        assert!(Policy::default().coerce_scalars);
+    }
+
+    #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+    struct FixedStruct {
+        #[serde(with = "crate::serde::fixed")]
+        value: RocStr<8>,
+        #[serde(with = "crate::serde::fixed")]
+        empty: RocStr<4>,
+    }
+
+    #[test]
+    fn fixed_serialized_size_should_not_depend_on_the_string() {
+        let short = FixedStruct {
+            value: RocStr::from("Ö"),
+            empty: RocStr::from(""),
+        };
+        let long = FixedStruct {
+            value: RocStr::from("Léopard"),
+            empty: RocStr::from("Löw"),
+        };
+
+        let short_bincode = bincode::serialize(&short).unwrap();
+        let long_bincode = bincode::serialize(&long).unwrap();
+        assert_eq!(short_bincode.len(), 4 + 8 + 4 + 4);
+        assert_eq!(long_bincode.len(), short_bincode.len());
+
+        let short_postcard = postcard::to_allocvec(&short).unwrap();
+        let long_postcard = postcard::to_allocvec(&long).unwrap();
+        assert_eq!(short_postcard.len(), 4 + 8 + 4 + 4);
+        assert_eq!(long_postcard.len(), short_postcard.len());
+        assert_eq!(short_postcard, short_bincode);
+    }
+
+    #[test]
+    fn fixed_deserialized_should_be_the_serialized_values() {
+        let value = FixedStruct {
+            value: RocStr::from("Léopard"),
+            empty: RocStr::from(""),
+        };
+
+        let serialized = bincode::serialize(&value).unwrap();
+        assert_eq!(
+            serialized,
+            [8, 0, 0, 0, b'L', 0xC3, 0xA9, b'o', b'p', b'a', b'r', b'd', 0, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            bincode::deserialize::<FixedStruct>(&serialized).unwrap(),
+            value
+        );
+
+        let serialized = postcard::to_allocvec(&value).unwrap();
+        assert_eq!(
+            postcard::from_bytes::<FixedStruct>(&serialized).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn fixed_deserialized_invalid_input_should_fail() {
+        let valid = [
+            2, 0, 0, 0, b'F', b'R', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert!(bincode::deserialize::<FixedStruct>(&valid).is_ok());
+
+        let too_long = [
+            9, 0, 0, 0, b'F', b'R', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert!(bincode::deserialize::<FixedStruct>(&too_long).is_err());
+
+        let invalid_utf8 = [
+            2, 0, 0, 0, 0xC3, b'R', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert!(bincode::deserialize::<FixedStruct>(&invalid_utf8).is_err());
+
+        let dirty_padding = [
+            2, 0, 0, 0, b'F', b'R', 0, b'X', 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert!(bincode::deserialize::<FixedStruct>(&dirty_padding).is_err());
+
+        assert!(postcard::from_bytes::<FixedStruct>(&valid[..12]).is_err());
+    }
+
+    #[test]
+    fn fixed_deserialized_short_sequence_should_fail() {
+        let deserialized = serde_json::from_str::<FixedStruct>(
+            r#"{"value":[2,0,0,0,70,82,0,0,0,0,0,0],"empty":[0,0,0,0]}"#,
+        );
+        assert!(deserialized.is_err());
+
+        let deserialized = serde_json::from_str::<FixedStruct>(
+            r#"{"value":[2,0,0,0,70,82,0,0,0,0,0,0],"empty":[0,0,0,0,0,0,0,0]}"#,
+        );
+        assert_eq!(deserialized.unwrap().value, "FR");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_serialize_path_should_still_be_usable() {
+        #[derive(Debug, Deserialize)]
+        struct Legacy {
+            #[serde(with = "crate::serialize::strict")]
+            value: RocStr<4>,
+        }
+
+        let policy = crate::serialize::Policy::new();
+        assert_eq!(policy, Policy::new());
+        assert_eq!(
+            serde_json::from_str::<Legacy>(r#"{"value":"FR"}"#)
+                .unwrap()
+                .value,
+            "FR"
+        );
+        assert!(serde_json::from_str::<Legacy>(r#"{"value":"FR-75"}"#).is_err());
+    }
+}